@@ -1,6 +1,6 @@
 //! See <https://en.wikipedia.org/wiki/Modular_arithmetic#Properties>.
 
-use gaussiant::{gaussint, GaussianInt};
+use gaussiant::{gaussint, Congruence, GaussianInt};
 
 fn main() {
     let a = gaussint!(2, -1);
@@ -18,17 +18,18 @@ fn main() {
     assert!(a2.congruent(b2, n));
 
     // The congruence relation satisfies all the conditions of an equivalence relation:
+    let mod_n = Congruence::new(n);
 
     // a ≡ a (mod n) (reflexivity)
-    assert!(a.congruent(a, n));
+    assert!(mod_n.are_congruent(a, a));
 
     // a ≡ b (mod n) if b ≡ a (mod n) for all a, b, and n. (symmetry)
-    assert_eq!(a.congruent(b, n), b.congruent(a, n));
+    assert_eq!(mod_n.are_congruent(a, b), mod_n.are_congruent(b, a));
 
     // a ≡ b (mod n) and b ≡ c (mod n) ⇒ a ≡ c (mod n) (transitivity)
     let c = gaussint!(5, 4);
-    assert!(b.congruent(c, n));
-    assert!(a.congruent(c, n));
+    assert!(mod_n.are_congruent(b, c));
+    assert!(mod_n.are_congruent(a, c));
 
     // If a₁ ≡ b₁ (mod n) and a₂ ≡ b₂ (mod n), or if a ≡ b (mod n), then:
 
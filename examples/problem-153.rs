@@ -1,28 +1,16 @@
 //! See https://projecteuler.net/problem=153
 
 use gaussiant::GaussianInt;
-use num_traits::Zero;
 
 fn main() {
     let n = 100;
     let mut big_sum = 0;
 
     for _n in 1..=n {
-        let set = gaussiant::get_g_ints(_n);
-        let mut divisors = vec![];
-        let mut sum = 0;
-
-        for z in set {
-            if z != GaussianInt::zero() && z.0.re != 0 {
-                if z.divides(GaussianInt::new(_n, 0)) {
-                    divisors.push(z);
-                    sum += z.0.re;
-                    sum += z.0.im;
-                    big_sum += z.0.re;
-                    big_sum += z.0.im;
-                }
-            }
-        }
+        let z = GaussianInt::new(_n, 0);
+        let divisors = GaussianInt::divisors_with_positive_real(z);
+        let sum = GaussianInt::sum_of_divisor_parts(z);
+        big_sum += sum;
 
         // format divisors as string
         let mut output = String::new();
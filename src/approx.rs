@@ -0,0 +1,95 @@
+use crate::GaussianInt;
+use num_complex::Complex;
+
+/// Approximates `z` by a quotient of Gaussian integers.
+///
+/// Returns a numerator and denominator *n*, *d*
+/// with *N*(*d*) ≤ `max_norm` minimizing |*n*/*d* - `z`|.
+/// For each candidate denominator (up to units),
+/// the best numerator is *d*·`z` rounded to the nearest lattice point.
+/// On ties, the denominator with the smaller norm wins.
+/// The fraction is returned in lowest terms,
+/// with the denominator as a [canonical associate].
+///
+/// [canonical associate]: GaussianInt::canonical_associate
+///
+/// # Panics
+///
+/// Panics if `max_norm` is zero.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # use num_complex::Complex;
+/// # fn main() {
+/// let (n, d) = gaussiant::rational_approx(Complex::new(0.5, 0.5), 10);
+/// // i / (1 + i) = (1 + i) / 2
+/// assert_eq!((n, d), (GaussianInt::new(0, 1), GaussianInt::new(1, 1)));
+/// # }
+/// ```
+pub fn rational_approx(z: Complex<f64>, max_norm: u64) -> (GaussianInt<i64>, GaussianInt<i64>) {
+    assert!(max_norm > 0, "max_norm must be positive");
+    let bound = crate::isqrt(max_norm) as i64;
+
+    let mut best = (GaussianInt::new(0, 0), GaussianInt::new(1, 0));
+    let mut best_error = f64::INFINITY;
+    for a in 1..=bound {
+        for b in 0..=bound {
+            let d = GaussianInt::new(a, b);
+            if d.norm_u64() > max_norm {
+                continue;
+            }
+            let dz = z * Complex::new(a as f64, b as f64);
+            let n = GaussianInt::new(dz.re.round() as i64, dz.im.round() as i64);
+            let error =
+                (Complex::new(n.0.re as f64, n.0.im as f64) - dz).norm() / (d.norm() as f64).sqrt();
+            if error < best_error || (error == best_error && d.norm() < best.1.norm()) {
+                best = (n, d);
+                best_error = error;
+            }
+        }
+    }
+    GaussianInt::reduce_fraction(best.0, best.1)
+}
+
+/// Returns the partial quotients of the nearest-integer continued fraction of `z`,
+/// at most `depth` of them.
+///
+/// Starting from *x* = `z`, each step takes *a* = *x* rounded to the nearest Gaussian integer,
+/// with halves rounded up in each part, and continues with 1/(*x* - *a*).
+/// Then `z` ≈ *a*₀ + 1/(*a*₁ + 1/(*a*₂ + ⋯)),
+/// and the approximation improves with each partial quotient.
+/// The expansion stops early when *x* is a Gaussian integer,
+/// as it is for a quotient of Gaussian integers,
+/// or when the next partial quotient would not fit in an `i64`.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # use num_complex::Complex;
+/// # fn main() {
+/// // (1 + i)/2 = (1 + i) + 1/(-1 + i)
+/// let quotients = gaussiant::continued_fraction_approx(Complex::new(0.5, 0.5), 10);
+/// assert_eq!(quotients, vec![GaussianInt::new(1, 1), GaussianInt::new(-1, 1)]);
+/// # }
+/// ```
+pub fn continued_fraction_approx(z: Complex<f64>, depth: usize) -> Vec<GaussianInt<i64>> {
+    let mut quotients = vec![];
+    let mut x = z;
+    while quotients.len() < depth {
+        let a = Complex::new((x.re + 0.5).floor(), (x.im + 0.5).floor());
+        let limit = i64::MAX as f64;
+        if !(a.re.abs() < limit && a.im.abs() < limit) {
+            break;
+        }
+        quotients.push(GaussianInt::new(a.re as i64, a.im as i64));
+        let rest = x - a;
+        if rest.re == 0.0 && rest.im == 0.0 {
+            break;
+        }
+        x = rest.inv();
+    }
+    quotients
+}
@@ -0,0 +1,44 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed};
+
+/// The class of all associates of a Gaussian integer.
+///
+/// Two `AssociateClass`es are equal (and hash equally)
+/// exactly when their Gaussian integers are associated,
+/// so they can be used as keys in a `HashMap` or `HashSet`
+/// to count values up to units.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use gaussiant::{AssociateClass, GaussianInt};
+/// # fn main() {
+/// let classes: HashSet<_> = gaussiant::get_g_primes(2).map(AssociateClass::new).collect();
+/// // 1+i, 1+2i, 2+i
+/// assert_eq!(classes.len(), 3);
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssociateClass<T: PrimInt + Integer>(GaussianInt<T>);
+
+impl<T: PrimInt + Integer + Signed> AssociateClass<T> {
+    /// Creates the associate class of `z`.
+    pub fn new(z: GaussianInt<T>) -> Self {
+        Self(z.canonical_associate())
+    }
+
+    /// Returns the canonical representative of the class.
+    ///
+    /// See [`GaussianInt::canonical_associate`].
+    pub fn representative(&self) -> GaussianInt<T> {
+        self.0
+    }
+}
+
+impl<T: PrimInt + Integer + Signed> From<GaussianInt<T>> for AssociateClass<T> {
+    fn from(z: GaussianInt<T>) -> Self {
+        Self::new(z)
+    }
+}
@@ -0,0 +1,187 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{One, PrimInt};
+
+/// An error returned when an arithmetic operation on [`GaussianInt`]s overflows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArithOverflow {
+    operation: &'static str,
+}
+
+impl ArithOverflow {
+    fn new(operation: &'static str) -> Self {
+        Self { operation }
+    }
+}
+
+impl std::fmt::Display for ArithOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "arithmetic overflow in Gaussian integer {}",
+            self.operation
+        )
+    }
+}
+
+impl std::error::Error for ArithOverflow {}
+
+impl<T: PrimInt + Integer> GaussianInt<T> {
+    /// Raises `self` to the power `exp`,
+    /// returning `None` if a part overflows.
+    ///
+    /// Uses exponentiation by squaring,
+    /// checking every multiplication.
+    /// If the result does not fit,
+    /// the computation can be retried with a wider type such as `i128`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(1, 1);
+    /// assert_eq!(z.checked_pow(4), Some(GaussianInt::new(-4, 0)));
+    /// assert_eq!(GaussianInt::<i8>::new(1, 1).checked_pow(16), None);
+    /// # }
+    /// ```
+    pub fn checked_pow(&self, mut exp: u32) -> Option<Self> {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul_parts(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul_parts(base)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Multiplies `self` by `other`, returning an error if a part overflows.
+    ///
+    /// The `*` operator wraps around on overflow in release builds;
+    /// this checks each product and sum in *ac* - *bd* and *ad* + *bc*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<i16> = GaussianInt::new(100, 100);
+    /// assert_eq!(z.try_mul(GaussianInt::new(1, -1)), Ok(GaussianInt::new(200, 0)));
+    /// assert!(GaussianInt::<i16>::new(400, 1).try_mul(z).is_err());
+    /// # }
+    /// ```
+    pub fn try_mul(&self, other: Self) -> Result<Self, ArithOverflow> {
+        self.checked_mul_parts(other)
+            .ok_or(ArithOverflow::new("multiplication"))
+    }
+
+    /// Tests whether `self` and `other` are [congruent](GaussianInt::congruent) modulo `modulus`,
+    /// returning `None` if a step overflows or `modulus` is zero.
+    ///
+    /// Testing divisibility by `modulus` multiplies by its conjugate,
+    /// so this can overflow even for values well inside the range of `T`.
+    /// A `None` result means the test should be retried with a wider type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(2, 5), GaussianInt::new(0, 1));
+    /// assert_eq!(a.checked_congruent(b, GaussianInt::new(1, 2)), Some(true));
+    ///
+    /// let a = GaussianInt::new(1 << 30, 1 << 30);
+    /// let m = GaussianInt::new(1 << 20, 1);
+    /// assert_eq!(a.checked_congruent(GaussianInt::new(0, 0), m), None);
+    /// # }
+    /// ```
+    pub fn checked_congruent(&self, other: Self, modulus: Self) -> Option<bool> {
+        let n = modulus.checked_norm()?;
+        if n.is_zero() {
+            return None;
+        }
+        let diff = self.checked_sub(other)?;
+        let conj = Self::new(modulus.0.re, T::zero().checked_sub(&modulus.0.im)?);
+        let z = diff.checked_mul_parts(conj)?;
+        Some((z.0.re % n).is_zero() && (z.0.im % n).is_zero())
+    }
+
+    /// Checked addition, returning `None` if a part overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u8> = GaussianInt::new(200, 1);
+    /// assert_eq!(z.checked_add(GaussianInt::new(55, 1)), Some(GaussianInt::new(255, 2)));
+    /// assert_eq!(z.checked_add(z), None);
+    /// # }
+    /// ```
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.0.re.checked_add(&other.0.re)?,
+            self.0.im.checked_add(&other.0.im)?,
+        ))
+    }
+
+    /// Checked subtraction, returning `None` if a part overflows.
+    ///
+    /// For unsigned types, this is `None` whenever a part of `other`
+    /// is larger than the corresponding part of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u32> = GaussianInt::new(5, 3);
+    /// assert_eq!(z.checked_sub(GaussianInt::new(2, 3)), Some(GaussianInt::new(3, 0)));
+    /// assert_eq!(z.checked_sub(GaussianInt::new(2, 4)), None);
+    /// # }
+    /// ```
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.0.re.checked_sub(&other.0.re)?,
+            self.0.im.checked_sub(&other.0.im)?,
+        ))
+    }
+
+    /// Saturating subtraction, clamping each part at the bounds of `T`.
+    ///
+    /// For unsigned types, each part is clamped at zero,
+    /// so the result stays a nonnegative Gaussian integer.
+    /// The parts saturate independently,
+    /// so the result is generally not the nearest representable value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u32> = GaussianInt::new(5, 3);
+    /// assert_eq!(z.saturating_sub(GaussianInt::new(2, 4)), GaussianInt::new(3, 0));
+    /// # }
+    /// ```
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        Self::new(
+            self.0.re.saturating_sub(other.0.re),
+            self.0.im.saturating_sub(other.0.im),
+        )
+    }
+
+    /// Checked multiplication, returning `None` on overflow.
+    pub(crate) fn checked_mul_parts(&self, other: Self) -> Option<Self> {
+        let (a, b) = (self.0.re, self.0.im);
+        let (c, d) = (other.0.re, other.0.im);
+        Some(Self::new(
+            a.checked_mul(&c)?.checked_sub(&b.checked_mul(&d)?)?,
+            a.checked_mul(&d)?.checked_add(&b.checked_mul(&c)?)?,
+        ))
+    }
+}
@@ -0,0 +1,112 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed, Zero};
+
+/// Congruence modulo a fixed Gaussian integer.
+///
+/// The data needed to reduce modulo `modulus` is computed once in [`Congruence::new`],
+/// so testing many values against the same modulus
+/// is cheaper than repeated calls to [`GaussianInt::congruent`].
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::{Congruence, GaussianInt, gaussint};
+/// # fn main() {
+/// // 2 + 5i ≡ i mod 1 + 2i
+/// let c = Congruence::new(gaussint!(1, 2));
+/// assert!(c.are_congruent(gaussint!(2, 5), gaussint!(0, 1)));
+/// assert_eq!(c.residue(gaussint!(2, 5)), c.residue(gaussint!(0, 1)));
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Congruence<T: PrimInt + Integer> {
+    modulus: GaussianInt<T>,
+    basis: ResidueBasis<T>,
+}
+
+impl<T: PrimInt + Integer + Signed> Congruence<T> {
+    /// Creates a congruence relation modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: GaussianInt<T>) -> Self {
+        Self {
+            modulus,
+            basis: ResidueBasis::new(modulus),
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> GaussianInt<T> {
+        self.modulus
+    }
+
+    /// Returns the canonical residue of `z`.
+    ///
+    /// See [`GaussianInt::reduce_mod`].
+    pub fn residue(&self, z: GaussianInt<T>) -> GaussianInt<T> {
+        self.basis.reduce(z)
+    }
+
+    /// Returns `true` if `a` ≡ `b` modulo the modulus.
+    pub fn are_congruent(&self, a: GaussianInt<T>, b: GaussianInt<T>) -> bool {
+        self.residue(a) == self.residue(b)
+    }
+}
+
+/// A basis of the lattice of multiples of a modulus *m* = *a* + *b*i.
+///
+/// With *N* = *a*² + *b*² and *g* = gcd(*a*, *b*),
+/// the multiples of *m* are spanned by (*N*/*g*, 0) and (`shift`, *g*),
+/// so every residue class has exactly one member *x* + *y*i
+/// with 0 ≤ *x* < *N*/*g* and 0 ≤ *y* < *g*.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ResidueBasis<T> {
+    width: T,
+    height: T,
+    shift: T,
+}
+
+impl<T: PrimInt + Integer + Signed> ResidueBasis<T> {
+    pub(crate) fn new(modulus: GaussianInt<T>) -> Self {
+        assert!(!modulus.is_zero(), "modulus must be nonzero");
+        let a = modulus.0.re;
+        let b = modulus.0.im;
+
+        // x·b + y·a = g, so x·m + y·(i·m) has imaginary part g.
+        let e = b.extended_gcd(&a);
+        let height = e.gcd;
+        let width = modulus.norm_value() / height;
+        let shift = (e.x * a - e.y * b).mod_floor(&width);
+
+        Self {
+            width,
+            height,
+            shift,
+        }
+    }
+
+    pub(crate) fn reduce(&self, z: GaussianInt<T>) -> GaussianInt<T> {
+        let k = z.0.im.div_floor(&self.height);
+        let re = (z.0.re - k * self.shift).mod_floor(&self.width);
+        let im = z.0.im - k * self.height;
+        GaussianInt::new(re, im)
+    }
+
+    /// Returns every canonical residue, ordered by imaginary and then real part.
+    pub(crate) fn residues(&self) -> Vec<GaussianInt<T>> {
+        let mut residues = vec![];
+        let mut y = T::zero();
+        while y < self.height {
+            let mut x = T::zero();
+            while x < self.width {
+                residues.push(GaussianInt::new(x, y));
+                x = x + T::one();
+            }
+            y = y + T::one();
+        }
+        residues
+    }
+}
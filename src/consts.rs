@@ -0,0 +1,32 @@
+use crate::GaussianInt;
+use num_complex::Complex;
+
+// `T::zero()` and `T::one()` are not `const`,
+// so the constants are defined for each primitive integer type.
+
+macro_rules! impl_consts {
+    ($($t:ty),*) => {$(
+        impl GaussianInt<$t> {
+            /// 0
+            pub const ZERO: Self = Self(Complex::new(0, 0));
+            /// 1
+            pub const ONE: Self = Self(Complex::new(1, 0));
+            /// *i*
+            pub const I: Self = Self(Complex::new(0, 1));
+        }
+    )*};
+}
+
+macro_rules! impl_signed_consts {
+    ($($t:ty),*) => {$(
+        impl GaussianInt<$t> {
+            /// -1
+            pub const NEG_ONE: Self = Self(Complex::new(-1, 0));
+            /// -*i*
+            pub const NEG_I: Self = Self(Complex::new(0, -1));
+        }
+    )*};
+}
+
+impl_consts!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_signed_consts!(i8, i16, i32, i64, i128, isize);
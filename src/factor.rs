@@ -0,0 +1,593 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{One, PrimInt, Signed, Zero};
+
+impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
+    /// Returns the factorization of `self` into a unit and Gaussian primes.
+    ///
+    /// Returns `(unit, factors)`, where each entry of `factors`
+    /// is a prime, given as its [canonical associate], together with its exponent.
+    /// The unit times the product of the factors is exactly `self`.
+    /// The factors are sorted by norm, and then by real and imaginary part,
+    /// so the result is deterministic.
+    /// Units have no prime factors.
+    ///
+    /// The factorization is found by factoring the norm over the rational integers:
+    /// 2 = -*i*(1+*i*)² ramifies,
+    /// rational primes *p* ≡ 3 mod 4 stay prime,
+    /// and rational primes *p* ≡ 1 mod 4 split into two conjugate Gaussian primes.
+    ///
+    /// To factor many values, [`GaussianFactorizer`] reuses a sieve between calls.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, or if the norm does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 10 = -(1 + i)²(1 + 2i)(2 + i)
+    /// let z = GaussianInt::new(10, 0);
+    /// assert_eq!(
+    ///     z.factorize(),
+    ///     (
+    ///         GaussianInt::new(-1, 0),
+    ///         vec![
+    ///             (GaussianInt::new(1, 1), 2),
+    ///             (GaussianInt::new(1, 2), 1),
+    ///             (GaussianInt::new(2, 1), 1),
+    ///         ]
+    ///     )
+    /// );
+    /// # }
+    /// ```
+    pub fn factorize(&self) -> (Self, Vec<(Self, u32)>) {
+        assert!(!self.is_zero(), "zero has no factorization");
+        self.factors_over(factor_rational(self.norm_u64()))
+    }
+
+    /// Returns the Gaussian Möbius function of `self`.
+    ///
+    /// This is 0 if some Gaussian prime divides `self` more than once,
+    /// and (-1)*ᵏ* if `self` is the product of a unit and *k* distinct primes.
+    /// Associates have the same value, and units have the value 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 5 = -i(1 + 2i)(2 + i)
+    /// assert_eq!(GaussianInt::new(5, 0).mobius(), 1);
+    /// // 2 = -i(1 + i)²
+    /// assert_eq!(GaussianInt::new(2, 0).mobius(), 0);
+    /// # }
+    /// ```
+    pub fn mobius(&self) -> i8 {
+        let (_, factors) = self.factorize();
+        if factors.iter().any(|&(_, k)| k > 1) {
+            return 0;
+        }
+        if factors.len() % 2 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Returns the radical of `self`, the product of its distinct Gaussian prime factors.
+    ///
+    /// The result is a [canonical associate],
+    /// and it is 1 for units.
+    /// `self` is squarefree exactly when its radical is an associate of `self`.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 12 = -(1 + i)⁴ · 3
+    /// assert_eq!(GaussianInt::new(12, 0).radical(), GaussianInt::new(3, 3));
+    /// # }
+    /// ```
+    pub fn radical(&self) -> Self {
+        let (_, factors) = self.factorize();
+        factors
+            .into_iter()
+            .fold(Self::one(), |product, (p, _)| product * p)
+            .canonical_associate()
+    }
+
+    /// Returns Euler's totient function of `self`:
+    /// the number of residue classes modulo `self` that are coprime to `self`.
+    ///
+    /// For `self` = *u*∏π*ᵏ*, this is ∏*N*(π)*ᵏ*⁻¹(*N*(π) - 1).
+    /// In particular it is *N*(π) - 1 for a Gaussian prime π, and 1 for a unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(3, 0).euler_phi(), 8);
+    /// // 2 = -i(1 + i)²
+    /// assert_eq!(GaussianInt::new(2, 0).euler_phi(), 2);
+    /// # }
+    /// ```
+    pub fn euler_phi(&self) -> u64 {
+        let (_, factors) = self.factorize();
+        factors.into_iter().fold(1, |phi, (p, k)| {
+            let n = p.norm_u64();
+            phi * n.pow(k - 1) * (n - 1)
+        })
+    }
+
+    /// Returns the sum of the norms of the divisors of `self`, taken up to units.
+    ///
+    /// Like [`euler_phi`](GaussianInt::euler_phi), this is multiplicative:
+    /// for `self` = *u*∏π*ᵏ*, it is ∏(1 + *N*(π) + … + *N*(π)*ᵏ*).
+    /// Compare [`sum_of_divisor_parts`](GaussianInt::sum_of_divisor_parts),
+    /// which sums the divisors themselves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // The divisors of 5 up to units are 1, 2 + i, 2 - i, and 5.
+    /// assert_eq!(GaussianInt::new(5, 0).sigma_norm(), 1 + 5 + 5 + 25);
+    /// # }
+    /// ```
+    pub fn sigma_norm(&self) -> u64 {
+        let (_, factors) = self.factorize();
+        factors.into_iter().fold(1, |sigma, (p, k)| {
+            let n = p.norm_u64();
+            sigma * (0..=k).map(|j| n.pow(j)).sum::<u64>()
+        })
+    }
+
+    /// Returns the conjugate of `self`.
+    ///
+    /// This is [`conj`](GaussianInt::conj), named for its role in factorizations:
+    /// a prime *p* ≡ 1 (mod 4) factors as π times its conjugate partner,
+    /// and the two are not associates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let q = GaussianInt::new(2, 1);
+    /// assert_eq!(q * q.conjugate_partner(), GaussianInt::new(5, 0));
+    /// # }
+    /// ```
+    pub fn conjugate_partner(&self) -> Self {
+        self.conj()
+    }
+
+    /// Returns the divisors of `z` with positive real part, sorted by real and then imaginary part.
+    ///
+    /// Each divisor *d* (up to units) has one or two associates with positive real part:
+    /// its [canonical associate], and also -*i* times it if that lies off the real axis.
+    /// These are the divisors counted in [Project Euler problem 153].
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    /// [Project Euler problem 153]: https://projecteuler.net/problem=153
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let divisors = GaussianInt::divisors_with_positive_real(GaussianInt::new(5, 0));
+    /// assert_eq!(
+    ///     divisors,
+    ///     vec![
+    ///         GaussianInt::new(1, -2),
+    ///         GaussianInt::new(1, 0),
+    ///         GaussianInt::new(1, 2),
+    ///         GaussianInt::new(2, -1),
+    ///         GaussianInt::new(2, 1),
+    ///         GaussianInt::new(5, 0),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn divisors_with_positive_real(z: Self) -> Vec<Self> {
+        let mut divisors = vec![];
+        for d in z.canonical_divisors() {
+            divisors.push(d);
+            if !d.0.im.is_zero() {
+                divisors.push(Self::new(d.0.im, -d.0.re));
+            }
+        }
+        divisors.sort_by_key(|d| (d.0.re, d.0.im));
+        divisors
+    }
+
+    /// Returns the sum of the real and imaginary parts
+    /// of the [divisors of `z` with positive real part](GaussianInt::divisors_with_positive_real).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 1 + (1 + 2i) + (1 - 2i) + (2 + i) + (2 - i) + 5
+    /// assert_eq!(GaussianInt::sum_of_divisor_parts(GaussianInt::new(5, 0)), 12);
+    /// # }
+    /// ```
+    pub fn sum_of_divisor_parts(z: Self) -> T {
+        Self::divisors_with_positive_real(z)
+            .into_iter()
+            .fold(T::zero(), |sum, d| sum + d.0.re + d.0.im)
+    }
+
+    /// Returns `self / divisor` if `divisor` divides `self`, and `None` otherwise.
+    ///
+    /// This is [`try_divides`](GaussianInt::try_divides) with the roles reversed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let ten = GaussianInt::new(10, 0);
+    /// assert_eq!(ten.complementary_divisor(GaussianInt::new(1, 3)), Some(GaussianInt::new(1, -3)));
+    /// assert_eq!(ten.complementary_divisor(GaussianInt::new(3, 0)), None);
+    /// # }
+    /// ```
+    pub fn complementary_divisor(&self, divisor: Self) -> Option<Self> {
+        divisor.try_divides(*self)
+    }
+
+    /// Returns the pairs `(d, self / d)` with `d` running over the divisors of `self`.
+    ///
+    /// Each divisor is taken up to units, as its [canonical associate],
+    /// and the pairs are sorted by the norm of `d` and then by its parts.
+    /// The two entries of each pair multiply to exactly `self`.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let pairs = GaussianInt::new(0, 2).divisor_pairs();
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         (GaussianInt::new(1, 0), GaussianInt::new(0, 2)),
+    ///         (GaussianInt::new(1, 1), GaussianInt::new(1, 1)),
+    ///         (GaussianInt::new(2, 0), GaussianInt::new(0, 1)),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn divisor_pairs(&self) -> Vec<(Self, Self)> {
+        let mut divisors = self.canonical_divisors();
+        divisors.sort_by_key(|d| (d.norm_u64(), d.0.re, d.0.im));
+        divisors.into_iter().map(|d| (d, *self / d)).collect()
+    }
+
+    /// Returns the canonical associates of the divisors of `self`, in no particular order.
+    pub(crate) fn canonical_divisors(&self) -> Vec<Self> {
+        let mut divisors = vec![Self::one()];
+        for (p, k) in self.factorize().1 {
+            let mut next = vec![];
+            for d in divisors {
+                let mut power = d;
+                for _ in 0..=k {
+                    next.push(power.canonical_associate());
+                    power *= p;
+                }
+            }
+            divisors = next;
+        }
+        divisors
+    }
+
+    /// Returns the norm as a `u64`, computed without overflow in `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the norm does not fit in a `u64`.
+    pub(crate) fn norm_u64(&self) -> u64 {
+        u64::try_from(self.norm_wide()).expect("norm should fit in a u64")
+    }
+
+    /// Lifts a factorization of the norm of `self` to a factorization of `self`.
+    fn factors_over(&self, norm_factors: Vec<(u64, u32)>) -> (Self, Vec<(Self, u32)>) {
+        let mut factors = vec![];
+        let mut z = *self;
+        for (p, k) in norm_factors {
+            if p == 2 {
+                factors.push((Self::new(T::one(), T::one()), k));
+            } else if p % 4 == 3 {
+                factors.push((Self::new(T::from(p).unwrap(), T::zero()), k / 2));
+            } else {
+                let (a, b) = split_prime(p);
+                let a = T::from(a).unwrap();
+                let b = T::from(b).unwrap();
+                // b + ai is the canonical associate of the conjugate of a + bi.
+                let (pi, pi_bar) = (Self::new(a, b), Self::new(b, a));
+                let mut e = 0;
+                while pi.divides(z) {
+                    z /= pi;
+                    e += 1;
+                }
+                if k > e {
+                    factors.push((pi_bar, k - e));
+                }
+                if e > 0 {
+                    factors.push((pi, e));
+                }
+            }
+        }
+        factors.sort_by_key(|(p, _)| (p.norm_u64(), p.0.re, p.0.im));
+
+        let mut product = Self::one();
+        for &(p, k) in &factors {
+            for _ in 0..k {
+                product *= p;
+            }
+        }
+        (*self / product, factors)
+    }
+}
+
+/// Factors Gaussian integers using a shared [`primal::Sieve`].
+///
+/// Building the sieve is the expensive part of factoring the norm,
+/// so a `GaussianFactorizer` should be created once and reused
+/// to factor many values.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::{GaussianFactorizer, GaussianInt};
+/// # fn main() {
+/// let factorizer = GaussianFactorizer::new(10_000);
+/// for z in gaussiant::get_g_ints(10).filter(|z| *z != GaussianInt::new(0, 0)) {
+///     assert_eq!(factorizer.factorize(z), z.factorize());
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct GaussianFactorizer {
+    sieve: primal::Sieve,
+}
+
+impl GaussianFactorizer {
+    /// Creates a factorizer suited to Gaussian integers with norm up to `max_norm`.
+    ///
+    /// Larger norms are still factored correctly,
+    /// but may fall back to trial division.
+    pub fn new(max_norm: usize) -> Self {
+        let limit = crate::isqrt(max_norm) + 1;
+        Self {
+            sieve: primal::Sieve::new(limit),
+        }
+    }
+
+    /// Returns the factorization of `z` into a unit and Gaussian primes.
+    ///
+    /// The result is the same as [`GaussianInt::factorize`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is zero, or if the norm does not fit in a `u64`.
+    #[allow(clippy::type_complexity)]
+    pub fn factorize<T>(&self, z: GaussianInt<T>) -> (GaussianInt<T>, Vec<(GaussianInt<T>, u32)>)
+    where
+        T: PrimInt + Integer + Signed,
+    {
+        assert!(!z.is_zero(), "zero has no factorization");
+        let norm = z.norm_u64();
+        let norm_factors = match self.sieve.factor(norm as usize) {
+            Ok(factors) => factors,
+            Err((rest, mut factors)) => {
+                factors.extend(
+                    factor_rational(rest as u64)
+                        .into_iter()
+                        .map(|(p, k)| (p as usize, k as usize)),
+                );
+                factors
+            }
+        };
+        let norm_factors = norm_factors
+            .into_iter()
+            .map(|(p, k)| (p as u64, k as u32))
+            .collect();
+        z.factors_over(norm_factors)
+    }
+}
+
+/// Writes a rational prime as a sum of two squares.
+///
+/// Returns `(a, b)` with *a* ≤ *b* and *a*² + *b*² = `p`
+/// if `p` is 2 or a prime congruent to 1 mod 4,
+/// and `None` otherwise.
+/// By Fermat's theorem on sums of two squares this representation exists and is unique.
+/// It corresponds to the factorization `p` = (*b* + *a*i)(*b* - *a*i).
+///
+/// Rather than searching,
+/// this finds a square root *x* of -1 mod `p`
+/// and runs the Euclidean algorithm on `p` and *x*,
+/// so it takes about as long as a modular exponentiation.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// assert_eq!(gaussiant::as_sum_of_two_squares(13), Some((2, 3)));
+/// assert_eq!(gaussiant::as_sum_of_two_squares(7), None);
+/// # }
+/// ```
+pub fn as_sum_of_two_squares(p: u64) -> Option<(u64, u64)> {
+    if p == 2 {
+        return Some((1, 1));
+    }
+    if p % 4 != 1 || !primal::is_prime(p) {
+        return None;
+    }
+    let (a, b) = split_prime(p);
+    Some((b, a))
+}
+
+/// Returns the Gaussian prime *q* = *a* + *b*i with *a* > *b* > 0
+/// and *q* times its conjugate equal to `p`,
+/// if `p` is a prime congruent to 1 mod 4.
+///
+/// For `p` = 2 this returns 1 + *i*, the one prime over 2 up to associates.
+/// Returns `None` if `p` is an inert prime (congruent to 3 mod 4) or not prime.
+///
+/// The other prime over `p` is the conjugate of *q*,
+/// which is associated to *b* + *a*i.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let q = gaussiant::gaussian_prime_over(13).unwrap();
+/// assert_eq!(q, GaussianInt::new(3, 2));
+/// assert_eq!(q * q.conj(), GaussianInt::new(13, 0));
+/// assert_eq!(gaussiant::gaussian_prime_over(7), None);
+/// # }
+/// ```
+pub fn gaussian_prime_over(p: u64) -> Option<GaussianInt<i64>> {
+    let (a, b) = as_sum_of_two_squares(p)?;
+    Some(GaussianInt::new(b as i64, a as i64))
+}
+
+/// Pairs each Gaussian integer in `primes` that is not real with its conjugate from `primes`.
+///
+/// Each pair is `(z, z.conj())` with `z` the earlier of the two in the slice,
+/// and pairs are in order of their first element.
+/// Each entry is used at most once, and conjugates must match exactly, not up to units.
+/// Entries with no conjugate partner left in the slice,
+/// including the real ones, which are their own conjugates, are not returned.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let primes = [GaussianInt::new(2, 1), GaussianInt::new(3, 0), GaussianInt::new(2, -1)];
+/// let pairs = gaussiant::group_conjugate_pairs(&primes);
+/// assert_eq!(pairs, vec![(GaussianInt::new(2, 1), GaussianInt::new(2, -1))]);
+/// # }
+/// ```
+pub fn group_conjugate_pairs<T>(primes: &[GaussianInt<T>]) -> Vec<(GaussianInt<T>, GaussianInt<T>)>
+where
+    T: PrimInt + Integer + Signed,
+{
+    let mut used = vec![false; primes.len()];
+    let mut pairs = vec![];
+    for (j, &z) in primes.iter().enumerate() {
+        if used[j] || z.is_rational() {
+            continue;
+        }
+        let partner = (j + 1..primes.len()).find(|&k| !used[k] && primes[k] == z.conj());
+        if let Some(k) = partner {
+            used[j] = true;
+            used[k] = true;
+            pairs.push((z, primes[k]));
+        }
+    }
+    pairs
+}
+
+/// Factors a rational integer by trial division,
+/// returning its prime factors in increasing order with their exponents.
+pub(crate) fn factor_rational(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p <= n / p {
+        let mut k = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            k += 1;
+        }
+        if k > 0 {
+            factors.push((p, k));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Returns `base`^`exp` mod `modulus`.
+pub(crate) fn pow_mod_u64(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let m = modulus as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Writes a prime *p* ≡ 1 mod 4 as *a*² + *b*² with *a* > *b* > 0.
+///
+/// Finds *x* with *x*² ≡ -1 mod *p*,
+/// then runs the Euclidean algorithm on *p* and *x*
+/// until the remainder drops below √*p* (the Hermite–Serret method).
+pub(crate) fn split_prime(p: u64) -> (u64, u64) {
+    let x = (2..p)
+        .map(|c| pow_mod_u64(c, (p - 1) / 4, p))
+        .find(|x| (*x as u128 * *x as u128) % p as u128 == (p - 1) as u128)
+        .expect("p should be a prime congruent to 1 mod 4");
+
+    let (mut r0, mut r1) = (p, x);
+    while (r1 as u128) * (r1 as u128) > p as u128 {
+        (r0, r1) = (r1, r0 % r1);
+    }
+    let a = r1;
+    let b = crate::isqrt(p - a * a);
+    debug_assert_eq!(a * a + b * b, p);
+    (a.max(b), a.min(b))
+}
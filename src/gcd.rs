@@ -0,0 +1,447 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{One, PrimInt, Signed, Zero};
+
+/// How to round each part of an exact quotient to an integer.
+///
+/// Used by [`GaussianInt::rem_with_rounding`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with halves rounded up.
+    /// This is the quotient of [`div_rem`](GaussianInt::div_rem)
+    /// and of the `/` and `%` operators.
+    NearestTiesUp,
+    /// Round to the nearest integer, with halves rounded to the even neighbor.
+    NearestTiesToEven,
+    /// Truncate toward zero.
+    TowardZero,
+}
+
+impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
+    /// Returns the greatest common divisor of `a` and `b`.
+    ///
+    /// The gcd is only defined up to units;
+    /// this returns the [canonical associate],
+    /// the unique associate with real part > 0 and imaginary part ≥ 0.
+    /// So a gcd on the imaginary axis is rotated onto the positive real axis:
+    /// the gcd of 2*i* and 4*i* is 2, not 2*i*.
+    /// The gcd of zero and zero is zero.
+    ///
+    /// Uses the Euclidean algorithm with the nearest-integer quotient,
+    /// which at least halves the norm of the remainder at each step.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 5 = (2 + i)(2 - i) and 3 + 4i = (2 + i)²
+    /// let a = GaussianInt::new(5, 0);
+    /// let b = GaussianInt::new(3, 4);
+    /// assert_eq!(GaussianInt::gcd(a, b), GaussianInt::new(2, 1));
+    /// # }
+    /// ```
+    pub fn gcd(a: Self, b: Self) -> Self {
+        Self::raw_gcd(a, b).canonical_associate()
+    }
+
+    /// Returns the [`gcd`](GaussianInt::gcd) of `a` and `b`
+    /// together with the unit that normalizes it.
+    ///
+    /// The Euclidean algorithm ends at some associate of the gcd;
+    /// this returns `(g, u)` where `g` is the canonical gcd
+    /// and `u` is the unit with `u` times that associate equal to `g`.
+    /// When `a` and `b` are both zero, `u` is 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // The algorithm ends at -2i, and i(-2i) = 2.
+    /// let (g, u) = GaussianInt::gcd_with_unit(GaussianInt::new(0, 2), GaussianInt::new(0, 4));
+    /// assert_eq!(g, GaussianInt::new(2, 0));
+    /// assert_eq!(u, GaussianInt::new(0, 1));
+    /// # }
+    /// ```
+    pub fn gcd_with_unit(a: Self, b: Self) -> (Self, Self) {
+        let raw = Self::raw_gcd(a, b);
+        let g = raw.canonical_associate();
+        let u = Self::units()
+            .into_iter()
+            .find(|&u| u * raw == g)
+            .expect("the gcd should be an associate of the Euclidean remainder");
+        (g, u)
+    }
+
+    /// Returns the points visited by the Euclidean algorithm for the gcd of `a` and `b`,
+    /// for plotting.
+    ///
+    /// The path starts with `a` and `b`, followed by each remainder in turn,
+    /// and ends with zero.
+    /// Each remainder has at most half the norm of the point before it,
+    /// so from `b` on the norms strictly decrease;
+    /// if N(`a`) > N(`b`) they decrease along the whole path.
+    /// The last nonzero point is the gcd up to a unit;
+    /// [`gcd_with_unit`](GaussianInt::gcd_with_unit) gives that unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a part does not fit in an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let path = GaussianInt::gcd_lattice_path(GaussianInt::new(5, 0), GaussianInt::new(3, 4));
+    /// assert_eq!(
+    ///     path,
+    ///     vec![
+    ///         GaussianInt::new(5, 0),
+    ///         GaussianInt::new(3, 4),
+    ///         GaussianInt::new(-2, -1),
+    ///         GaussianInt::new(0, 0),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn gcd_lattice_path(a: Self, b: Self) -> Vec<GaussianInt<i64>> {
+        let point = |z: Self| {
+            GaussianInt::new(
+                z.0.re.to_i64().expect("part should fit in an i64"),
+                z.0.im.to_i64().expect("part should fit in an i64"),
+            )
+        };
+        let mut path = vec![point(a), point(b)];
+        let (mut a, mut b) = (a, b);
+        while !b.is_zero() {
+            let r = a - b * a.div_nearest(b);
+            path.push(point(r));
+            a = b;
+            b = r;
+        }
+        path
+    }
+
+    /// Runs the Euclidean algorithm, returning the last nonzero remainder.
+    fn raw_gcd(a: Self, b: Self) -> Self {
+        let (mut a, mut b) = (a, b);
+        while !b.is_zero() {
+            let r = a - b * a.div_nearest(b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Returns the greatest common divisor of `a` and `b`,
+    /// or `None` if an intermediate step of the computation would overflow.
+    ///
+    /// Even when the gcd is small, the nearest-integer division
+    /// multiplies by the conjugate of the divisor,
+    /// which can overflow for inputs with large parts.
+    /// A `None` result means the computation should be retried with a wider type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let a = GaussianInt::new(1 << 30, 1);
+    /// let b = GaussianInt::new(3, 1 << 30);
+    /// assert_eq!(GaussianInt::<i32>::checked_gcd(a, b), None);
+    ///
+    /// let a = GaussianInt::new(1_i64 << 30, 1);
+    /// let b = GaussianInt::new(3, 1 << 30);
+    /// assert_eq!(GaussianInt::checked_gcd(a, b), Some(GaussianInt::gcd(a, b)));
+    /// # }
+    /// ```
+    pub fn checked_gcd(a: Self, b: Self) -> Option<Self> {
+        let (mut a, mut b) = (a, b);
+        while !b.is_zero() {
+            let q = a.checked_div_nearest(b)?;
+            let r = a.checked_sub(b.checked_mul_parts(q)?)?;
+            a = b;
+            b = r;
+        }
+        Some(a.canonical_associate())
+    }
+
+    /// Returns the gcd `g` of `a` and `b` together with Bézout coefficients `x` and `y`,
+    /// so that `a * x + b * y == g`.
+    ///
+    /// As for [`gcd`](GaussianInt::gcd), `g` is the [canonical associate].
+    /// The coefficients are not unique; these are the ones found by
+    /// the extended Euclidean algorithm with nearest-integer quotients.
+    /// In debug builds the result is checked with [`verify_bezout`](GaussianInt::verify_bezout).
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let a = GaussianInt::new(5, 0);
+    /// let b = GaussianInt::new(3, 4);
+    /// let (g, x, y) = GaussianInt::extended_gcd(a, b);
+    /// assert_eq!(g, GaussianInt::new(2, 1));
+    /// assert_eq!(a * x + b * y, g);
+    /// # }
+    /// ```
+    pub fn extended_gcd(a: Self, b: Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (a, b);
+        let (mut old_x, mut x) = (Self::one(), Self::zero());
+        let (mut old_y, mut y) = (Self::zero(), Self::one());
+        while !r.is_zero() {
+            let q = old_r.div_nearest(r);
+            (old_r, r) = (r, old_r - q * r);
+            (old_x, x) = (x, old_x - q * x);
+            (old_y, y) = (y, old_y - q * y);
+        }
+
+        let (mut g, mut x, mut y) = (old_r, old_x, old_y);
+        if !g.is_zero() {
+            let u = g.canonical_associate() / g;
+            (g, x, y) = (g * u, x * u, y * u);
+        }
+        debug_assert!(Self::verify_bezout(a, b, g, x, y));
+        (g, x, y)
+    }
+
+    /// Returns `true` if `a * x + b * y == g`.
+    ///
+    /// This checks the output of [`extended_gcd`](GaussianInt::extended_gcd).
+    /// If also `g` divides both `a` and `b`, then `g` is a gcd of `a` and `b`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(2, 0), GaussianInt::new(3, 0));
+    /// let (x, y) = (GaussianInt::new(-1, 0), GaussianInt::new(1, 0));
+    /// assert!(GaussianInt::verify_bezout(a, b, GaussianInt::new(1, 0), x, y));
+    /// assert!(!GaussianInt::verify_bezout(a, b, GaussianInt::new(1, 0), y, x));
+    /// # }
+    /// ```
+    pub fn verify_bezout(a: Self, b: Self, g: Self, x: Self, y: Self) -> bool {
+        a * x + b * y == g
+    }
+
+    /// Divides `a` and `b` by their gcd.
+    ///
+    /// The result is multiplied by a unit so that the denominator
+    /// is a [canonical associate], which makes it deterministic.
+    /// If `a` and `b` are already coprime, only this normalization is applied.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let a = GaussianInt::new(2, 2);
+    /// let b = GaussianInt::new(4, 4);
+    /// let (n, d) = GaussianInt::reduce_fraction(a, b);
+    /// assert_eq!((n, d), (GaussianInt::new(1, 0), GaussianInt::new(2, 0)));
+    /// # }
+    /// ```
+    pub fn reduce_fraction(a: Self, b: Self) -> (Self, Self) {
+        assert!(!b.is_zero(), "denominator must be nonzero");
+        let g = Self::gcd(a, b);
+        let (a, b) = (a / g, b / g);
+        let d = b.canonical_associate();
+        let u = d / b;
+        (a * u, d)
+    }
+
+    /// Returns the quotient and remainder of `self` by `other` from one nearest-integer division.
+    ///
+    /// The quotient is [`round_div`](GaussianInt::round_div)
+    /// and the remainder is [`rem_euclid`](GaussianInt::rem_euclid),
+    /// so `quotient * other + remainder == self`
+    /// and the remainder has norm at most half the norm of `other`.
+    /// The `/` and `%` operators return the two halves of this pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(7, 2), GaussianInt::new(2, 1));
+    /// let (q, r) = a.div_rem(b);
+    /// assert_eq!((q, r), (GaussianInt::new(3, -1), GaussianInt::new(0, 1)));
+    /// assert_eq!(q * b + r, a);
+    /// # }
+    /// ```
+    pub fn div_rem(&self, other: Self) -> (Self, Self) {
+        let q = self.round_div(other);
+        (q, *self - other * q)
+    }
+
+    /// Returns the quotient of `self` by `other`, rounded to the nearest Gaussian integer.
+    ///
+    /// Each part of the exact quotient is rounded to the nearest integer,
+    /// with halves rounded up.
+    /// This is the quotient used by [`gcd`](GaussianInt::gcd),
+    /// and [`rem_euclid`](GaussianInt::rem_euclid) is the matching remainder.
+    /// It agrees with the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // (7 + 2i) / (2 + i) = 3.2 - 0.6i
+    /// let (a, b) = (GaussianInt::new(7, 2), GaussianInt::new(2, 1));
+    /// assert_eq!(a.round_div(b), GaussianInt::new(3, -1));
+    /// assert_eq!(a / b, a.round_div(b));
+    /// # }
+    /// ```
+    pub fn round_div(&self, other: Self) -> Self {
+        assert!(!other.is_zero(), "division by zero");
+        self.div_nearest(other)
+    }
+
+    /// Returns the remainder of `self` by `other` for the quotient [`round_div`](GaussianInt::round_div).
+    ///
+    /// The remainder has norm at most half the norm of `other`,
+    /// which is what makes the Euclidean algorithm terminate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(7, 2), GaussianInt::new(2, 1));
+    /// assert_eq!(a.rem_euclid(b), GaussianInt::new(0, 1));
+    /// assert_eq!(a.round_div(b) * b + a.rem_euclid(b), a);
+    /// # }
+    /// ```
+    pub fn rem_euclid(&self, other: Self) -> Self {
+        *self - other * self.round_div(other)
+    }
+
+    /// Returns the [nearest-integer quotient](GaussianInt::round_div) of `self` by `other`,
+    /// or `None` if `other` is zero or the computation overflows.
+    ///
+    /// Unlike the `/` operator, which panics when dividing by zero,
+    /// this never panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(7, 2), GaussianInt::new(2, 1));
+    /// assert_eq!(a.checked_div(b), Some(GaussianInt::new(3, -1)));
+    /// assert_eq!(a.checked_div(GaussianInt::new(0, 0)), None);
+    /// # }
+    /// ```
+    pub fn checked_div(&self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        self.checked_div_nearest(other)
+    }
+
+    /// Returns the remainder of `self` by `other`,
+    /// for the quotient whose parts are rounded according to `mode`.
+    ///
+    /// Texts differ on which remainder to take when a part of the exact quotient
+    /// is halfway between two integers; this makes the choice explicit.
+    /// With [`RoundingMode::NearestTiesUp`] this is [`rem_euclid`](GaussianInt::rem_euclid)
+    /// and `self % other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::{GaussianInt, RoundingMode};
+    /// # fn main() {
+    /// // (1 + i) / 2 = 1/2 + i/2 is a tie in both parts.
+    /// let (a, b) = (GaussianInt::new(1, 1), GaussianInt::new(2, 0));
+    /// assert_eq!(a.rem_with_rounding(b, RoundingMode::NearestTiesUp), GaussianInt::new(-1, -1));
+    /// assert_eq!(a.rem_with_rounding(b, RoundingMode::NearestTiesToEven), GaussianInt::new(1, 1));
+    /// assert_eq!(a.rem_with_rounding(b, RoundingMode::NearestTiesUp), a % b);
+    /// assert_eq!(a.rem_with_rounding(b, RoundingMode::TowardZero), GaussianInt::new(1, 1));
+    /// # }
+    /// ```
+    pub fn rem_with_rounding(&self, other: Self, mode: RoundingMode) -> Self {
+        assert!(!other.is_zero(), "division by zero");
+        *self - other * self.div_with_rounding(other, mode)
+    }
+
+    /// Returns the quotient of `self` by `other` with each part rounded according to `mode`.
+    fn div_with_rounding(&self, other: Self, mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::NearestTiesUp => self.div_nearest(other),
+            RoundingMode::NearestTiesToEven => {
+                let n = other.norm_value();
+                let z = *self * other.conj();
+                let two = T::one() + T::one();
+                let round = |x: T| {
+                    let (q, r) = (two * x + n).div_mod_floor(&(two * n));
+                    if r.is_zero() && q.is_odd() {
+                        q - T::one()
+                    } else {
+                        q
+                    }
+                };
+                Self::new(round(z.0.re), round(z.0.im))
+            }
+            RoundingMode::TowardZero => {
+                let n = other.norm_value();
+                let z = *self * other.conj();
+                Self::new(z.0.re / n, z.0.im / n)
+            }
+        }
+    }
+
+    /// Returns the quotient of `self` by `other`, rounded to the nearest Gaussian integer.
+    ///
+    /// Each part of the exact quotient is rounded to the nearest integer,
+    /// with halves rounded up.
+    pub(crate) fn div_nearest(&self, other: Self) -> Self {
+        let n = other.norm_value();
+        let z = *self * other.conj();
+        let two = T::one() + T::one();
+        let round = |x: T| (two * x + n).div_floor(&(two * n));
+        Self::new(round(z.0.re), round(z.0.im))
+    }
+
+    /// Like [`div_nearest`](GaussianInt::div_nearest),
+    /// but returns `None` on overflow.
+    pub(crate) fn checked_div_nearest(&self, other: Self) -> Option<Self> {
+        let n = other.checked_norm()?;
+        let conj = Self::new(other.0.re, T::zero().checked_sub(&other.0.im)?);
+        let z = self.checked_mul_parts(conj)?;
+        let two_n = n.checked_add(&n)?;
+        let round = |x: T| Some(x.checked_add(&x)?.checked_add(&n)?.div_floor(&two_n));
+        Some(Self::new(round(z.0.re)?, round(z.0.im)?))
+    }
+}
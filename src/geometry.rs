@@ -0,0 +1,714 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed};
+use std::collections::{HashSet, VecDeque};
+
+impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
+    /// Returns the dot product of `self` and `other` as vectors in the plane.
+    ///
+    /// This is the real part of `self` times the conjugate of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(3, 4);
+    /// assert_eq!(z.dot(GaussianInt::new(-4, 3)), 0);
+    /// assert_eq!(z.dot(z), z.norm_value());
+    /// # }
+    /// ```
+    pub fn dot(&self, other: Self) -> T {
+        self.0.re * other.0.re + self.0.im * other.0.im
+    }
+
+    /// Returns the cross product of `self` and `other` as vectors in the plane.
+    ///
+    /// This is the imaginary part of the conjugate of `self` times `other`.
+    /// It is positive when `other` is counterclockwise from `self`,
+    /// negative when it is clockwise, and zero when they are parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(1, 2);
+    /// assert_eq!(z.cross(GaussianInt::new(-2, -4)), 0);
+    /// assert_eq!(GaussianInt::new(1, 0).cross(GaussianInt::new(0, 1)), 1);
+    /// # }
+    /// ```
+    pub fn cross(&self, other: Self) -> T {
+        self.0.re * other.0.im - self.0.im * other.0.re
+    }
+
+    /// Returns `true` if `a`, `b`, and `c` lie on one line.
+    ///
+    /// This holds when the [cross product](GaussianInt::cross)
+    /// of `b - a` and `c - a` is zero,
+    /// including when two of the points coincide.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b) = (GaussianInt::new(1, 1), GaussianInt::new(3, 2));
+    /// assert!(GaussianInt::are_collinear(a, b, GaussianInt::new(-1, 0)));
+    /// assert!(!GaussianInt::are_collinear(a, b, GaussianInt::new(0, 0)));
+    /// # }
+    /// ```
+    pub fn are_collinear(a: Self, b: Self, c: Self) -> bool {
+        (b - a).cross(c - a).is_zero()
+    }
+
+    /// Returns twice the signed area of the triangle with vertices `a`, `b`, and `c`.
+    ///
+    /// Twice the area of a lattice triangle is an integer,
+    /// so this is exact.
+    /// It is positive when the vertices run counterclockwise,
+    /// negative when they run clockwise,
+    /// and zero when they are [collinear](GaussianInt::are_collinear).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (a, b, c) = (GaussianInt::new(0, 0), GaussianInt::new(4, 0), GaussianInt::new(0, 3));
+    /// assert_eq!(GaussianInt::triangle_area_doubled(a, b, c), 12);
+    /// assert_eq!(GaussianInt::triangle_area_doubled(a, c, b), -12);
+    /// # }
+    /// ```
+    pub fn triangle_area_doubled(a: Self, b: Self, c: Self) -> T {
+        (b - a).cross(c - a)
+    }
+
+    /// Returns the number of lattice points on the boundary of the polygon
+    /// with the given vertices, taken in order.
+    ///
+    /// The edge from *v* to *w* passes through gcd(|*w* - *v*|) + 1 lattice points
+    /// counting both ends, where the gcd is of the horizontal and vertical distances,
+    /// so the boundary has the sum of these gcds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let square = [
+    ///     GaussianInt::new(0, 0),
+    ///     GaussianInt::new(2, 0),
+    ///     GaussianInt::new(2, 2),
+    ///     GaussianInt::new(0, 2),
+    /// ];
+    /// assert_eq!(GaussianInt::boundary_lattice_points(&square), 8);
+    /// # }
+    /// ```
+    pub fn boundary_lattice_points(vertices: &[Self]) -> T {
+        edges(vertices).fold(T::zero(), |sum, (v, w)| {
+            let d = w - v;
+            sum + d.0.re.gcd(&d.0.im)
+        })
+    }
+
+    /// Returns the number of lattice points strictly inside the polygon
+    /// with the given vertices, taken in order.
+    ///
+    /// The vertices must describe a simple polygon,
+    /// listed either clockwise or counterclockwise.
+    /// By Pick's theorem, a lattice polygon with area *A*
+    /// and *B* [boundary lattice points](GaussianInt::boundary_lattice_points)
+    /// has *A* - *B*/2 + 1 interior points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than three vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let triangle = [
+    ///     GaussianInt::new(0, 0),
+    ///     GaussianInt::new(4, 0),
+    ///     GaussianInt::new(0, 4),
+    /// ];
+    /// // (1, 1), (2, 1), and (1, 2)
+    /// assert_eq!(GaussianInt::interior_lattice_points(&triangle), 3);
+    /// # }
+    /// ```
+    pub fn interior_lattice_points(vertices: &[Self]) -> T {
+        assert!(
+            vertices.len() >= 3,
+            "a polygon needs at least three vertices"
+        );
+        let area_doubled = edges(vertices)
+            .fold(T::zero(), |sum, (v, w)| sum + v.cross(w))
+            .abs();
+        let two = T::one() + T::one();
+        (area_doubled - Self::boundary_lattice_points(vertices) + two) / two
+    }
+
+    /// Returns the lattice point nearest to the orthogonal projection of `self`
+    /// onto the line through `a` and `b`.
+    ///
+    /// The projection is computed exactly in rational arithmetic,
+    /// and then each part is rounded to the nearest integer, with halves rounded up,
+    /// as in nearest-integer Gaussian division.
+    /// The result need not lie on the line,
+    /// unless the line's direction is a multiple of 1, *i*, or 1±*i*.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` equals `b`, or if a part does not fit in an `i64`.
+    /// Also panics if an intermediate value overflows an `i128`,
+    /// which can happen once `a` and `b` are about `2^42` or more apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(3, 1);
+    /// let (a, b) = (GaussianInt::new(0, 0), GaussianInt::new(1, 1));
+    /// assert_eq!(z.project_onto_line(a, b), GaussianInt::new(2, 2));
+    /// # }
+    /// ```
+    pub fn project_onto_line(&self, a: Self, b: Self) -> GaussianInt<i64> {
+        assert!(a != b, "a line needs two distinct points");
+        let wide = |z: Self| (z.0.re.to_i128().unwrap(), z.0.im.to_i128().unwrap());
+        let (z, a, b) = (wide(*self), wide(a), wide(b));
+        let overflow = "projection should fit in an i128";
+        let sub = |x: i128, y: i128| x.checked_sub(y).expect(overflow);
+        let dot = |u: (i128, i128), v: (i128, i128)| {
+            let (x, y) = (u.0.checked_mul(v.0), u.1.checked_mul(v.1));
+            x.zip(y)
+                .and_then(|(x, y)| x.checked_add(y))
+                .expect(overflow)
+        };
+        let d = (sub(b.0, a.0), sub(b.1, a.1));
+        let (num, den) = (dot((sub(z.0, a.0), sub(z.1, a.1)), d), dot(d, d));
+        let g = num.gcd(&den);
+        let (num, den) = (num / g, den / g);
+        // a + (num/den)d = a + qd + (r/den)d, with 0 <= r < den,
+        // so only the last term needs rounding, and it stays small
+        let (q, r) = num.div_mod_floor(&den);
+        let round = |a: i128, d: i128| {
+            let whole = q.checked_mul(d).and_then(|x| x.checked_add(a));
+            let twice = r.checked_mul(d).and_then(|x| x.checked_mul(2));
+            let frac = twice
+                .and_then(|x| x.checked_add(den))
+                .zip(den.checked_mul(2))
+                .map(|(x, y)| Integer::div_floor(&x, &y));
+            let x = whole.zip(frac).and_then(|(w, f)| w.checked_add(f));
+            i64::try_from(x.expect(overflow)).expect("part should fit in an i64")
+        };
+        GaussianInt::new(round(a.0, d.0), round(a.1, d.1))
+    }
+
+    /// Reflects `self` across the real axis, negating the imaginary part.
+    ///
+    /// This is the same as [`conj`](GaussianInt::conj).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 3).reflect_real(), GaussianInt::new(2, -3));
+    /// # }
+    /// ```
+    pub fn reflect_real(&self) -> Self {
+        self.conj()
+    }
+
+    /// Reflects `self` across the imaginary axis, negating the real part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 3).reflect_imag(), GaussianInt::new(-2, 3));
+    /// # }
+    /// ```
+    pub fn reflect_imag(&self) -> Self {
+        Self::new(-self.0.re, self.0.im)
+    }
+
+    /// Reflects `self` across the diagonal line through 0 and 1 + *i*,
+    /// swapping the real and imaginary parts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 3).reflect_diagonal(), GaussianInt::new(3, 2));
+    /// # }
+    /// ```
+    pub fn reflect_diagonal(&self) -> Self {
+        Self::new(self.0.im, self.0.re)
+    }
+
+    /// Returns the distinct images of `self` under the symmetries of the square lattice.
+    ///
+    /// The dihedral group of the square has eight elements:
+    /// the four rotations, multiplication by the [units](GaussianInt::units),
+    /// and the four reflections, the rotations of the conjugate.
+    /// The images come in that order, starting with `self`, with repeats removed.
+    /// There are eight unless `self` lies on an axis or a diagonal,
+    /// where there are four, or is zero.
+    /// Gaussian primality is unchanged by all eight symmetries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 1).d4_orbit().len(), 8);
+    /// assert_eq!(
+    ///     GaussianInt::new(1, 1).d4_orbit(),
+    ///     vec![
+    ///         GaussianInt::new(1, 1),
+    ///         GaussianInt::new(-1, 1),
+    ///         GaussianInt::new(-1, -1),
+    ///         GaussianInt::new(1, -1),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn d4_orbit(&self) -> Vec<Self> {
+        let mut orbit = vec![];
+        let images = self
+            .unit_orbit()
+            .take(4)
+            .chain(self.conj().unit_orbit().take(4));
+        for z in images {
+            if !orbit.contains(&z) {
+                orbit.push(z);
+            }
+        }
+        orbit
+    }
+
+    /// Returns the four lattice neighbors of `self`.
+    ///
+    /// These are `self` plus each [unit](GaussianInt::units),
+    /// in the same order: `self` + 1, `self` - 1, `self` + *i*, `self` - *i*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(2, 3);
+    /// assert_eq!(
+    ///     z.neighbors(),
+    ///     [
+    ///         GaussianInt::new(3, 3),
+    ///         GaussianInt::new(1, 3),
+    ///         GaussianInt::new(2, 4),
+    ///         GaussianInt::new(2, 2),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn neighbors(&self) -> [Self; 4] {
+        Self::units().map(|u| *self + u)
+    }
+
+    /// Returns the eight lattice neighbors of `self`, including diagonals.
+    ///
+    /// The first four are the [`neighbors`](GaussianInt::neighbors);
+    /// they are followed by `self` + 1 + *i*, `self` + 1 - *i*,
+    /// `self` - 1 + *i*, and `self` - 1 - *i*.
+    pub fn neighbors8(&self) -> [Self; 8] {
+        let [a, b, c, d] = self.neighbors();
+        let one = T::one();
+        [
+            a,
+            b,
+            c,
+            d,
+            *self + Self::new(one, one),
+            *self + Self::new(one, -one),
+            *self + Self::new(-one, one),
+            *self + Self::new(-one, -one),
+        ]
+    }
+
+    /// Returns the position of `self` in the square spiral of the lattice,
+    /// starting from 0 at the origin.
+    ///
+    /// The spiral follows the Ulam convention:
+    /// it steps from 0 to 1, then turns counterclockwise,
+    /// so 1, 1+*i*, *i*, -1+*i*, -1, … are numbered 1, 2, 3, 4, 5, ….
+    /// The square ring max(|*a*|, |*b*|) = *k* holds the indices
+    /// (2*k* - 1)² up to (2*k* + 1)² - 1,
+    /// beginning just above the positive real axis at *k* - (*k* - 1)*i*.
+    ///
+    /// [`from_spiral_index`](GaussianInt::from_spiral_index) is the inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a part does not fit in an `i64`,
+    /// or if the index does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(0, 0).to_spiral_index(), 0);
+    /// assert_eq!(GaussianInt::new(1, 1).to_spiral_index(), 2);
+    /// assert_eq!(GaussianInt::new(1, -1).to_spiral_index(), 8);
+    /// assert_eq!(GaussianInt::new(2, -1).to_spiral_index(), 9);
+    /// # }
+    /// ```
+    pub fn to_spiral_index(&self) -> u64 {
+        let a = self.0.re.to_i64().unwrap();
+        let b = self.0.im.to_i64().unwrap();
+        let k = a.unsigned_abs().max(b.unsigned_abs());
+        if k == 0 {
+            return 0;
+        }
+        let (a, b, k) = (a as i128, b as i128, k as i128);
+        let offset = if a == k && b > -k {
+            b + k - 1
+        } else if b == k {
+            3 * k - 1 - a
+        } else if a == -k {
+            5 * k - 1 - b
+        } else {
+            7 * k - 1 + a
+        };
+        let start = (2 * k - 1) * (2 * k - 1);
+        u64::try_from(start + offset).expect("spiral index should fit in a u64")
+    }
+
+    /// Returns a reduced basis of the lattice spanned by `a` and `b`.
+    ///
+    /// The lattice is the set of integer combinations *m*`a` + *n*`b`.
+    /// This is Gauss's reduction algorithm:
+    /// repeatedly subtract the nearest integer multiple of the shorter vector from the longer one,
+    /// until that no longer makes it shorter.
+    /// The result `(v1, v2)` spans the same lattice with N(`v1`) ≤ N(`v2`),
+    /// `v1` is a shortest nonzero vector of the lattice,
+    /// and `v2` is a shortest vector independent of `v1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are parallel, so that they do not span a lattice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (v1, v2) = GaussianInt::reduce_basis(GaussianInt::new(3, 1), GaussianInt::new(7, 3));
+    /// assert_eq!((v1, v2), (GaussianInt::new(1, 1), GaussianInt::new(1, -1)));
+    /// # }
+    /// ```
+    pub fn reduce_basis(a: Self, b: Self) -> (Self, Self) {
+        assert!(!a.cross(b).is_zero(), "basis vectors must not be parallel");
+        let two = T::one() + T::one();
+        let (mut a, mut b) = (a, b);
+        loop {
+            if b.norm_value() < a.norm_value() {
+                std::mem::swap(&mut a, &mut b);
+            }
+            let n = a.norm_value();
+            let q = Integer::div_floor(&(two * b.dot(a) + n), &(two * n));
+            if q.is_zero() {
+                return (a, b);
+            }
+            b -= Self::new(a.0.re * q, a.0.im * q);
+        }
+    }
+
+    /// Returns a nonzero vector of minimal norm in the lattice spanned by `a` and `b`.
+    ///
+    /// This is the first vector of the [reduced basis](GaussianInt::reduce_basis).
+    /// A lattice has at least two shortest vectors, `v` and -`v`;
+    /// which one is returned is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let v = GaussianInt::shortest_vector(GaussianInt::new(10, 1), GaussianInt::new(21, 2));
+    /// assert_eq!(v.norm(), 1);
+    /// # }
+    /// ```
+    pub fn shortest_vector(a: Self, b: Self) -> Self {
+        Self::reduce_basis(a, b).0
+    }
+}
+
+impl GaussianInt<i64> {
+    /// Returns the Gaussian integer at position `n` of the square spiral.
+    ///
+    /// This is the inverse of [`to_spiral_index`](GaussianInt::to_spiral_index),
+    /// which describes the spiral.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::from_spiral_index(3), GaussianInt::new(0, 1));
+    /// let z = GaussianInt::new(-4, 7);
+    /// assert_eq!(GaussianInt::from_spiral_index(z.to_spiral_index()), z);
+    /// # }
+    /// ```
+    pub fn from_spiral_index(n: u64) -> Self {
+        if n == 0 {
+            return Self::new(0, 0);
+        }
+        let k = crate::isqrt(n).div_ceil(2);
+        let offset = n - (2 * k - 1) * (2 * k - 1);
+        let (side, t) = (offset / (2 * k), (offset % (2 * k)) as i64);
+        let k = k as i64;
+        match side {
+            0 => Self::new(k, t - k + 1),
+            1 => Self::new(k - 1 - t, k),
+            2 => Self::new(-k, k - 1 - t),
+            _ => Self::new(t - k + 1, -k),
+        }
+    }
+}
+
+/// The largest absolute value of a part considered by [`approximate_rotation`].
+const ROTATION_SEARCH_BOUND: i64 = 12;
+
+/// Returns a Gaussian integer whose argument approximates `degrees`.
+///
+/// Multiplying by the result rotates by approximately `degrees`
+/// (and scales by the square root of its norm).
+///
+/// This is a heuristic lattice approximation, not an exact rotation:
+/// it picks the direction *a* + *b*i with gcd(*a*, *b*) = 1
+/// and |*a*|, |*b*| ≤ 12 whose argument is closest to the requested angle,
+/// preferring the smaller norm on ties.
+/// Multiples of 90° give a unit exactly.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// assert_eq!(gaussiant::approximate_rotation(90.0), GaussianInt::new(0, 1));
+/// assert_eq!(gaussiant::approximate_rotation(45.0), GaussianInt::new(1, 1));
+/// // 3 + 4i is about 53.13°
+/// assert_eq!(gaussiant::approximate_rotation(53.0), GaussianInt::new(3, 4));
+/// # }
+/// ```
+pub fn approximate_rotation(degrees: f64) -> GaussianInt<i64> {
+    let target = degrees.to_radians();
+    let n = ROTATION_SEARCH_BOUND;
+
+    let mut best = GaussianInt::new(1, 0);
+    let mut best_error = f64::INFINITY;
+    for a in -n..=n {
+        for b in -n..=n {
+            if num_integer::gcd(a, b) != 1 {
+                continue;
+            }
+            let z = GaussianInt::new(a, b);
+            let error = angle_between((b as f64).atan2(a as f64), target);
+            if error < best_error || (error == best_error && z.norm() < best.norm()) {
+                best = z;
+                best_error = error;
+            }
+        }
+    }
+    best
+}
+
+/// Returns the lattice points connected to `start` through points satisfying `predicate`.
+///
+/// This is a breadth-first search over the [`neighbors`](GaussianInt::neighbors) of each point,
+/// so the points are returned in order of their distance in steps from `start`,
+/// beginning with `start` itself.
+/// Points more than `max_steps` steps from `start` are not explored.
+/// If `start` does not satisfy `predicate`, the result is empty.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// // The cluster of Gaussian primes containing 1 + i
+/// let cluster = gaussiant::flood_fill(
+///     GaussianInt::new(1, 1),
+///     |z| z.is_gaussian_prime() && z.norm() <= 10,
+///     100,
+/// );
+/// assert_eq!(
+///     cluster,
+///     vec![
+///         GaussianInt::new(1, 1),
+///         GaussianInt::new(2, 1),
+///         GaussianInt::new(1, 2),
+///     ]
+/// );
+/// # }
+/// ```
+pub fn flood_fill<F: Fn(GaussianInt<i64>) -> bool>(
+    start: GaussianInt<i64>,
+    predicate: F,
+    max_steps: usize,
+) -> Vec<GaussianInt<i64>> {
+    if !predicate(start) {
+        return vec![];
+    }
+
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut points = vec![];
+    while let Some((z, steps)) = queue.pop_front() {
+        points.push(z);
+        if steps == max_steps {
+            continue;
+        }
+        for w in z.neighbors() {
+            if !visited.contains(&w) && predicate(w) {
+                visited.insert(w);
+                queue.push_back((w, steps + 1));
+            }
+        }
+    }
+    points
+}
+
+/// Returns the lattice points on the line segment from `a` to `b`,
+/// as drawn by Bresenham's line algorithm.
+///
+/// Both endpoints are included,
+/// and consecutive points differ by a unit or by a unit times 1+*i*.
+/// The number of points is one more than the larger of the horizontal and vertical distances.
+/// Points are computed as the iterator is advanced, so long segments take no extra memory.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let line: Vec<_> = gaussiant::lattice_line(GaussianInt::new(0, 0), GaussianInt::new(4, 2)).collect();
+/// assert_eq!(
+///     line,
+///     vec![
+///         GaussianInt::new(0, 0),
+///         GaussianInt::new(1, 1),
+///         GaussianInt::new(2, 1),
+///         GaussianInt::new(3, 2),
+///         GaussianInt::new(4, 2),
+///     ]
+/// );
+/// # }
+/// ```
+pub fn lattice_line(
+    a: GaussianInt<i64>,
+    b: GaussianInt<i64>,
+) -> impl Iterator<Item = GaussianInt<i64>> {
+    let (x0, y0) = (a.0.re, a.0.im);
+    let (x1, y1) = (b.0.re, b.0.im);
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+    let mut err = dx + dy;
+
+    // The next point to yield, or `None` once `b` has been yielded.
+    let mut next = Some((x0, y0));
+    std::iter::from_fn(move || {
+        let (x, y) = next?;
+        next = if x == x1 && y == y1 {
+            None
+        } else {
+            let e2 = 2 * err;
+            let (mut x, mut y) = (x, y);
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+            Some((x, y))
+        };
+        Some(GaussianInt::new(x, y))
+    })
+}
+
+/// Sorts `points` by argument, counterclockwise starting from the positive real axis.
+///
+/// Arguments are taken in [0, 2π), so 1 comes first and 1-*i* last among the units.
+/// The origin, which has no argument, is placed before every other point.
+/// The sort is stable, so points with the same argument keep their order.
+///
+/// Points are compared exactly with integer cross products,
+/// rather than by computing `atan2` in floating point.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let mut points = [
+///     GaussianInt::new(0, -1),
+///     GaussianInt::new(-3, 0),
+///     GaussianInt::new(2, 1),
+///     GaussianInt::new(0, 0),
+/// ];
+/// gaussiant::sort_by_argument(&mut points);
+/// assert_eq!(
+///     points,
+///     [
+///         GaussianInt::new(0, 0),
+///         GaussianInt::new(2, 1),
+///         GaussianInt::new(-3, 0),
+///         GaussianInt::new(0, -1),
+///     ]
+/// );
+/// # }
+/// ```
+pub fn sort_by_argument(points: &mut [GaussianInt<i64>]) {
+    // 0 for the origin, 1 for arguments in [0, π), 2 for [π, 2π)
+    let half = |z: &GaussianInt<i64>| match (z.0.re, z.0.im) {
+        (0, 0) => 0,
+        (re, im) if im > 0 || (im == 0 && re > 0) => 1,
+        _ => 2,
+    };
+    points.sort_by(|a, b| {
+        half(a).cmp(&half(b)).then_with(|| {
+            let cross = a.0.re as i128 * b.0.im as i128 - a.0.im as i128 * b.0.re as i128;
+            0.cmp(&cross)
+        })
+    });
+}
+
+/// Returns the edges of the polygon with the given vertices,
+/// including the one closing it from the last vertex back to the first.
+fn edges<T: PrimInt + Integer>(
+    vertices: &[GaussianInt<T>],
+) -> impl Iterator<Item = (GaussianInt<T>, GaussianInt<T>)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(v, w)| (*v, *w))
+}
+
+/// Returns the absolute difference of two angles, in [0, π].
+fn angle_between(x: f64, y: f64) -> f64 {
+    let d = (x - y).rem_euclid(std::f64::consts::TAU);
+    d.min(std::f64::consts::TAU - d)
+}
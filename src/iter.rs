@@ -0,0 +1,189 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed};
+
+/// Adapters for iterators of Gaussian integers.
+///
+/// This trait is implemented for every iterator over [`GaussianInt`]s.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::{GaussianInt, GaussianIntIteratorExt};
+/// # fn main() {
+/// let primes: Vec<_> = gaussiant::get_g_ints(2)
+///     .gaussian_primes()
+///     .canonical_associates()
+///     .collect();
+/// assert!(primes.iter().all(|p| p.is_gaussian_prime() && p.0.re > 0));
+/// # }
+/// ```
+pub trait GaussianIntIteratorExt<T: PrimInt + Integer>:
+    Iterator<Item = GaussianInt<T>> + Sized
+{
+    /// Filters the iterator down to Gaussian primes.
+    fn gaussian_primes(self) -> impl Iterator<Item = GaussianInt<T>> {
+        self.filter(|z| z.is_gaussian_prime())
+    }
+
+    /// Maps each element to its [canonical associate](GaussianInt::canonical_associate).
+    fn canonical_associates(self) -> impl Iterator<Item = GaussianInt<T>>
+    where
+        T: Signed,
+    {
+        self.map(|z| z.canonical_associate())
+    }
+}
+
+impl<T, I> GaussianIntIteratorExt<T> for I
+where
+    T: PrimInt + Integer,
+    I: Iterator<Item = GaussianInt<T>>,
+{
+}
+
+/// Returns the element of `iter` with the smallest norm.
+///
+/// If several elements have the smallest norm, the first is returned.
+/// Returns `None` if the iterator is empty.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let p = gaussiant::min_by_norm(gaussiant::get_g_primes(5)).unwrap();
+/// assert_eq!(p.norm(), 2);
+/// # }
+/// ```
+pub fn min_by_norm<T, I>(iter: I) -> Option<GaussianInt<T>>
+where
+    T: PrimInt + Integer,
+    I: IntoIterator<Item = GaussianInt<T>>,
+{
+    iter.into_iter().min_by_key(|z| z.norm_value())
+}
+
+/// Returns the element of `iter` with the largest norm.
+///
+/// If several elements have the largest norm, the last is returned.
+/// Returns `None` if the iterator is empty.
+pub fn max_by_norm<T, I>(iter: I) -> Option<GaussianInt<T>>
+where
+    T: PrimInt + Integer,
+    I: IntoIterator<Item = GaussianInt<T>>,
+{
+    iter.into_iter().max_by_key(|z| z.norm_value())
+}
+
+/// Returns the Gaussian primes in increasing order of norm, without end.
+///
+/// Primes of the same norm come in order of real part and then imaginary part.
+/// Unlike [`get_g_primes`](crate::get_g_primes), no bound is needed up front,
+/// so this suits queries like "the first *k* primes".
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let norms: Vec<_> = gaussiant::gaussian_primes().take(13).map(|p| p.norm()).collect();
+/// assert_eq!(norms, [2, 2, 2, 2, 5, 5, 5, 5, 5, 5, 5, 5, 9]);
+/// # }
+/// ```
+pub fn gaussian_primes() -> impl Iterator<Item = GaussianInt<i64>> {
+    by_norm().filter(|z| z.is_gaussian_prime())
+}
+
+/// Returns one Gaussian prime from each class of associates and conjugates
+/// with norm at most `max_norm`, in increasing order of norm.
+///
+/// Each prime yielded has real part at least its imaginary part, which is nonnegative:
+/// 1 + *i*, then *a* + *bi* with *a* > *b* > 0 for each prime *p* ≡ 1 (mod 4),
+/// then the rational primes *p* ≡ 3 (mod 4) on the real axis.
+/// This is the octant usually drawn in prime plots and listed in prime tables.
+///
+/// Every prime yielded is a [prime representative](GaussianInt::is_prime_representative),
+/// but the converse fails: *b* + *ai* is a representative too,
+/// so [`prime_count_up_to_norm`](crate::prime_count_up_to_norm) counts
+/// each split prime twice where this yields it once.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// let primes: Vec<_> = gaussiant::first_quadrant_primes(25).collect();
+/// assert_eq!(
+///     primes,
+///     vec![
+///         GaussianInt::new(1, 1),
+///         GaussianInt::new(2, 1),
+///         GaussianInt::new(3, 0),
+///         GaussianInt::new(3, 2),
+///         GaussianInt::new(4, 1),
+///     ]
+/// );
+/// # }
+/// ```
+pub fn first_quadrant_primes(max_norm: u64) -> impl Iterator<Item = GaussianInt<i64>> {
+    let r = crate::isqrt(max_norm) as i64;
+    let mut primes = vec![];
+    for a in 1..=r {
+        for b in 0..=a {
+            let z = GaussianInt::new(a, b);
+            if z.norm_value() as u64 <= max_norm && z.is_gaussian_prime() {
+                primes.push(z);
+            }
+        }
+    }
+    primes.sort_by_key(|z| (z.norm_value(), z.0.re));
+    primes.into_iter()
+}
+
+impl GaussianInt<i64> {
+    /// Returns the Gaussian integers of norm exactly `n`,
+    /// in order of real part and then imaginary part.
+    ///
+    /// There are none unless `n` is a sum of two squares,
+    /// and then a multiple of four of them for `n` > 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::with_norm(5).count(), 8);
+    /// assert_eq!(GaussianInt::with_norm(3).count(), 0);
+    /// let first = GaussianInt::with_norm(25).next();
+    /// assert_eq!(first, Some(GaussianInt::new(-5, 0)));
+    /// # }
+    /// ```
+    pub fn with_norm(n: u64) -> impl Iterator<Item = Self> {
+        solutions_with_norm(n).into_iter()
+    }
+}
+
+/// Returns every Gaussian integer in increasing order of norm,
+/// shell by shell, starting from zero.
+fn by_norm() -> impl Iterator<Item = GaussianInt<i64>> {
+    (0..).flat_map(GaussianInt::with_norm)
+}
+
+/// Returns the Gaussian integers of norm `n`, sorted by real and then imaginary part.
+fn solutions_with_norm(n: u64) -> Vec<GaussianInt<i64>> {
+    let r = crate::isqrt(n) as i64;
+    let mut solutions = vec![];
+    for a in -r..=r {
+        let rest = n - a.unsigned_abs().pow(2);
+        let b = crate::isqrt(rest);
+        if b * b == rest {
+            let b = b as i64;
+            solutions.push(GaussianInt::new(a, -b));
+            if b != 0 {
+                solutions.push(GaussianInt::new(a, b));
+            }
+        }
+    }
+    solutions
+}
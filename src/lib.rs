@@ -14,18 +14,95 @@ extern crate doc_comment;
 #[cfg(doctest)]
 doctest!("../README.md", readme);
 
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
 use std::str::FromStr;
+use std::sync::OnceLock;
 
 use num_complex::{Complex, ParseComplexError};
 use num_integer::Integer;
 use num_traits::{Num, One, PrimInt, Signed, Zero};
 
+mod approx;
+mod associate;
+mod checked;
+mod congruence;
+mod consts;
+mod factor;
+mod gcd;
+mod geometry;
+mod iter;
+#[cfg(feature = "nalgebra")]
+mod linalg;
+mod modular;
 mod ops;
+mod parse;
+mod render;
+mod search;
+mod wrapping;
+
+pub use approx::{continued_fraction_approx, rational_approx};
+pub use associate::AssociateClass;
+pub use checked::ArithOverflow;
+pub use congruence::Congruence;
+pub use factor::{
+    as_sum_of_two_squares, gaussian_prime_over, group_conjugate_pairs, GaussianFactorizer,
+};
+pub use gcd::RoundingMode;
+pub use geometry::{approximate_rotation, flood_fill, lattice_line, sort_by_argument};
+pub use iter::{
+    first_quadrant_primes, gaussian_primes, max_by_norm, min_by_norm, GaussianIntIteratorExt,
+};
+pub use parse::ParseGaussianIntError;
+pub use render::render_primes_ascii;
 
 /// A Gaussian integer is a complex number whose real and imaginary parts are both integers.
+///
+/// For each primitive integer type there are associated constants
+/// `ZERO`, `ONE`, and `I`, plus `NEG_ONE` and `NEG_I` for signed types.
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// type G = GaussianInt<i32>;
+/// assert_eq!(G::I * G::I, G::NEG_ONE);
+/// # }
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct GaussianInt<T: PrimInt + Integer>(pub Complex<T>);
 
+/// The reason a Gaussian integer is a Gaussian prime.
+///
+/// See [`GaussianInt::gaussian_prime_kind`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GaussianPrimeKind {
+    /// One of the parts is zero,
+    /// and the absolute value of the other is a rational prime of the form 4*n* + 3.
+    RationalPrime3Mod4,
+    /// Both parts are nonzero, and the norm is a rational prime.
+    NormIsPrime,
+}
+
+/// A classification of a Gaussian integer by the factorization of its norm.
+///
+/// See [`GaussianInt::norm_class`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NormClass {
+    /// The norm is 0; only zero itself.
+    Zero,
+    /// The norm is 1; the units 1, -1, *i*, and -*i*.
+    Unit,
+    /// The norm is a rational prime *p*.
+    /// These are the Gaussian primes lying over 2 or over a prime *p* ≡ 1 (mod 4).
+    NormPrime,
+    /// The norm is the square of a rational prime *p*.
+    /// This includes the inert Gaussian primes *p* ≡ 3 (mod 4),
+    /// but also composites such as 2 = -*i*(1 + *i*)² and 3 + 4*i* = (2 + *i*)².
+    NormPrimeSquared,
+    /// The norm has any other factorization, so the Gaussian integer is composite.
+    Composite,
+}
+
 /// Creates a new [`GaussianInt`].
 ///
 /// # Example
@@ -64,6 +141,116 @@ impl<T: PrimInt + Integer> GaussianInt<T> {
         Self(Complex::new(r, i))
     }
 
+    /// Creates the Gaussian integer `n` + 0*i* from a rational integer.
+    ///
+    /// This is the same as `GaussianInt::from(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::from_rational(5), GaussianInt::new(5, 0));
+    ///
+    /// let z: GaussianInt<i32> = 5.into();
+    /// assert_eq!(z, GaussianInt::from_rational(5));
+    /// # }
+    /// ```
+    pub fn from_rational(n: T) -> Self {
+        Self::new(n, T::zero())
+    }
+
+    /// Creates a new [`GaussianInt`] from parts of another integer type,
+    /// returning `None` if either part does not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::<i8>::try_new(100, -3), Some(GaussianInt::new(100, -3)));
+    /// assert_eq!(GaussianInt::<i8>::try_new(200, 0), None);
+    /// assert_eq!(GaussianInt::<u32>::try_new(1, -1), None);
+    /// # }
+    /// ```
+    pub fn try_new<U: PrimInt>(r: U, i: U) -> Option<Self> {
+        Some(Self::new(num_traits::cast(r)?, num_traits::cast(i)?))
+    }
+
+    /// Creates the Gaussian integer `n` + 0*i*,
+    /// returning `None` if `n` does not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::<i8>::from_isize(-5), Some(GaussianInt::new(-5, 0)));
+    /// assert_eq!(GaussianInt::<u8>::from_isize(-5), None);
+    /// # }
+    /// ```
+    pub fn from_isize(n: isize) -> Option<Self> {
+        Self::try_new(n, 0)
+    }
+
+    /// Returns a reference to the underlying [`Complex`] value.
+    ///
+    /// Prefer this (or [`into_inner`](GaussianInt::into_inner))
+    /// to the `.0` field, which may become private in a future version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(3, 4);
+    /// assert_eq!(z.inner().l1_norm(), 7);
+    /// # }
+    /// ```
+    pub fn inner(&self) -> &Complex<T> {
+        &self.0
+    }
+
+    /// Consumes `self`, returning the underlying [`Complex`] value.
+    pub fn into_inner(self) -> Complex<T> {
+        self.0
+    }
+
+    /// Returns the real and imaginary parts as `f64` values, for plotting and other geometry.
+    ///
+    /// Parts larger than 2⁵³ in absolute value are rounded to the nearest `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(3i64, -4);
+    /// assert_eq!(z.as_f64_pair(), (3.0, -4.0));
+    /// # }
+    /// ```
+    pub fn as_f64_pair(&self) -> (f64, f64) {
+        (self.0.re.to_f64().unwrap(), self.0.im.to_f64().unwrap())
+    }
+
+    /// Convert to polar form (r, theta), such that
+    /// `self = r * exp(i * theta)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let c = GaussianInt::new(0, 1);
+    /// // The polar form of *i* is (1, π/2).
+    /// assert_eq!(c.to_polar(), (1f64, std::f64::consts::PI / 2f64));
+    /// # }
+    /// ```
+    pub fn to_polar(&self) -> (f64, f64) {
+        let (a, b) = self.as_f64_pair();
+        Complex::new(a, b).to_polar()
+    }
+
     /// Given a Gaussian integer z₀, called a *modulus*,
     /// two Gaussian integers z₁, z₂ are *congruent modulo z₀*,
     /// if their difference is a multiple of z₀.
@@ -81,7 +268,554 @@ impl<T: PrimInt + Integer> GaussianInt<T> {
     /// # }
     /// ```
     pub fn congruent(&self, other: Self, modulus: Self) -> bool {
-        (*self - other) % modulus == Self::zero()
+        Self::from((*self - other).0 % modulus.0) == Self::zero()
+    }
+
+    /// Returns `self` squared.
+    ///
+    /// Computes (*a* + *b*)(*a* - *b*) + 2*ab*i,
+    /// which takes two multiplications
+    /// rather than the four of a general product `self * self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(2, 1);
+    /// assert_eq!(z.square(), GaussianInt::new(3, 4));
+    /// # }
+    /// ```
+    pub fn square(&self) -> Self {
+        let (a, b) = (self.0.re, self.0.im);
+        let ab = a * b;
+        Self::new((a + b) * (a - b), ab + ab)
+    }
+
+    /// Raises `self` to the power `exp`.
+    ///
+    /// Uses exponentiation by squaring, with [`square`](GaussianInt::square) for the squaring steps.
+    /// The computation is not checked for overflow;
+    /// see [`checked_pow`](GaussianInt::checked_pow).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // (1 + i)⁴ = (2i)² = -4
+    /// assert_eq!(GaussianInt::new(1, 1).pow(4), GaussianInt::new(-4, 0));
+    /// # }
+    /// ```
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.square();
+            }
+        }
+        result
+    }
+
+    /// Returns the powers `self`, `self`², `self`³, ... with norm at most `max_norm`.
+    ///
+    /// The norm of each power is the norm of `self` times that of the last,
+    /// so for norm at least 2 the list stops at the first power whose norm exceeds `max_norm`.
+    /// Zero and the units have powers of norm at most 1 forever;
+    /// for them the list stops before the first repeated power,
+    /// so it holds each distinct power once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a power with norm at most `max_norm` does not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // The powers of 1 + i spiral outward, doubling the norm each time.
+    /// let powers = GaussianInt::new(1, 1).powers_until_norm(16);
+    /// assert_eq!(
+    ///     powers,
+    ///     vec![
+    ///         GaussianInt::new(1, 1),
+    ///         GaussianInt::new(0, 2),
+    ///         GaussianInt::new(-2, 2),
+    ///         GaussianInt::new(-4, 0),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn powers_until_norm(&self, max_norm: u64) -> Vec<Self> {
+        let max_norm = max_norm as i128;
+        let norm = self.norm_wide();
+        let mut powers = vec![];
+        let mut power = *self;
+        while norm <= max_norm && !powers.contains(&power) {
+            powers.push(power);
+            // Check the norm of the next power before computing it, so it cannot overflow `T`.
+            let next_norm = norm.checked_mul(power.norm_wide());
+            if next_norm.is_none_or(|n| n > max_norm) {
+                break;
+            }
+            power = power
+                .checked_mul_parts(*self)
+                .expect("power should fit in the integer type");
+        }
+        powers
+    }
+
+    /// Returns the norm *a*² + *b*² as a value of type `T`.
+    ///
+    /// Unlike [`norm`](GaussianInt::norm), this does not convert to `usize`,
+    /// so it works for unsigned types too.
+    /// The computation is not checked for overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(2, 7);
+    /// assert_eq!(z.norm_value(), 53);
+    /// # }
+    /// ```
+    pub fn norm_value(&self) -> T {
+        self.0.re * self.0.re + self.0.im * self.0.im
+    }
+
+    /// Returns the norm as a value of type `T`,
+    /// or `None` if computing it would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 7).checked_norm(), Some(53));
+    /// assert_eq!(GaussianInt::new(i32::MAX, 1).checked_norm(), None);
+    /// # }
+    /// ```
+    pub fn checked_norm(&self) -> Option<T> {
+        let a = self.0.re.checked_mul(&self.0.re)?;
+        let b = self.0.im.checked_mul(&self.0.im)?;
+        a.checked_add(&b)
+    }
+
+    /// Returns the norm, computed in `i128`.
+    ///
+    /// Widening the parts first means the norm is correct
+    /// for large values of `GaussianInt<i64>`,
+    /// where [`norm_value`](GaussianInt::norm_value) would overflow
+    /// and [`checked_norm`](GaussianInt::checked_norm) would return `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a part does not fit in an `i128`, or the norm overflows it.
+    /// For parts of 64 bits or fewer,
+    /// this only happens when both parts are `i64::MIN`, or with large `u64` parts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(i64::MAX, i64::MIN);
+    /// assert_eq!(z.checked_norm(), None);
+    /// let m = i64::MAX as i128;
+    /// assert_eq!(z.norm_wide(), m * m + (m + 1) * (m + 1));
+    /// # }
+    /// ```
+    pub fn norm_wide(&self) -> i128 {
+        let a = self.0.re.to_i128().expect("part should fit in an i128");
+        let b = self.0.im.to_i128().expect("part should fit in an i128");
+        let norm = || a.checked_mul(a)?.checked_add(b.checked_mul(b)?);
+        norm().expect("norm should fit in an i128")
+    }
+
+    /// Returns `true` if the norm of `self` divides the norm of `other`.
+    ///
+    /// Since the norm is multiplicative, *N*(*z*) | *N*(*w*) whenever *z* | *w*.
+    /// The converse does not hold:
+    /// 2+*i* and 2-*i* both have norm 5, but neither divides the other.
+    /// This makes `norm_divides` a cheap necessary condition
+    /// for [`divides`](GaussianInt::divides),
+    /// useful for pruning candidates before doing a Gaussian division.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(2, 1);
+    /// let w = GaussianInt::new(2, -1);
+    /// assert!(z.norm_divides(w));
+    /// assert!(!z.divides(w));
+    /// # }
+    /// ```
+    pub fn norm_divides(&self, other: Self) -> bool {
+        let n = self.norm_value();
+        !n.is_zero() && (other.norm_value() % n).is_zero()
+    }
+
+    /// Returns `true` if the norm of `self` is a rational prime.
+    ///
+    /// The norm is computed [without overflow](GaussianInt::norm_wide),
+    /// so this works for large parts where `norm_value` would overflow.
+    /// A Gaussian integer off the axes is a Gaussian prime exactly when this holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the norm does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert!(GaussianInt::new(2, 1).norm_is_prime());
+    /// // 3 is a Gaussian prime, but its norm 9 is not prime.
+    /// assert!(!GaussianInt::new(3, 0).norm_is_prime());
+    /// # }
+    /// ```
+    pub fn norm_is_prime(&self) -> bool {
+        let n = u64::try_from(self.norm_wide()).expect("norm should fit in a u64");
+        is_prime_u64(n)
+    }
+
+    /// Tests for [Gaussian primality].
+    ///
+    /// A Gaussian integer *a* + *b*i is a *Gaussian prime* if and only if either:
+    ///
+    /// 1. one of *a*, *b* is zero,
+    ///    and the absolute value of the other
+    ///    is a prime number of the form 4*n* + 3
+    ///    (with *n* a nonnegative integer)
+    /// 2. both *a* and *b* are nonzero,
+    ///    and *a*² + *b*² is a prime number
+    ///    (which will not be of the form 4*n* + 3).
+    ///
+    /// Units are never prime: 1 and -1 fail the first condition since 1 is not prime,
+    /// and *i* and -*i* fail it in the same way.
+    /// On the other hand 1 + *i*, written `GaussianInt::new(1, 1)`,
+    /// is prime because its norm 2 is prime.
+    ///
+    /// [Gaussian primality]: https://en.wikipedia.org/wiki/Gaussian_integer#Gaussian_primes
+    ///
+    /// # Panics
+    ///
+    /// Panics if both parts are nonzero and the norm does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(2, 7);
+    /// assert!(z.is_gaussian_prime());
+    /// # }
+    /// ```
+    pub fn is_gaussian_prime(&self) -> bool {
+        self.gaussian_prime_kind().is_some()
+    }
+
+    /// Tests whether `self` is irreducible:
+    /// not zero or a unit, and not a product of two non-units.
+    ///
+    /// In a unique factorization domain such as ℤ\[*i*\],
+    /// the irreducible elements are exactly the primes,
+    /// so this is the same test as [`is_gaussian_prime`](GaussianInt::is_gaussian_prime),
+    /// under the name used in many algebra texts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert!(GaussianInt::new(1, 1).is_irreducible());
+    /// // 5 = (2 + i)(2 - i)
+    /// assert!(!GaussianInt::new(5, 0).is_irreducible());
+    /// # }
+    /// ```
+    pub fn is_irreducible(&self) -> bool {
+        self.is_gaussian_prime()
+    }
+
+    /// Tests each of `values` for [Gaussian primality](GaussianInt::is_gaussian_prime).
+    ///
+    /// One sieve, sized to the largest norm, is shared by all the tests,
+    /// which is much faster than testing many values one at a time.
+    /// The sieve covers at most the integers up to 2²⁴;
+    /// values with larger norms are tested individually.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let values = [GaussianInt::new(2, 1), GaussianInt::new(5, 0), GaussianInt::new(0, 7)];
+    /// assert_eq!(GaussianInt::are_gaussian_prime(&values), vec![true, false, true]);
+    /// # }
+    /// ```
+    pub fn are_gaussian_prime(values: &[Self]) -> Vec<bool> {
+        let max_norm = values
+            .iter()
+            .filter_map(|z| {
+                let (a, b) = (abs_u64(z.0.re), abs_u64(z.0.im));
+                a.checked_mul(a)?.checked_add(b.checked_mul(b)?)
+            })
+            .max()
+            .unwrap_or(0);
+        let sieve = primal::Sieve::new(max_norm.min(BATCH_SIEVE_LIMIT) as usize);
+        values
+            .iter()
+            .map(|z| {
+                z.gaussian_prime_kind_with(|n| sieve_is_prime(&sieve, n))
+                    .is_some()
+            })
+            .collect()
+    }
+
+    /// Tests for [Gaussian primality], returning which condition makes `self` prime.
+    ///
+    /// Returns `None` if `self` is not a Gaussian prime.
+    /// See [`is_gaussian_prime`](GaussianInt::is_gaussian_prime) for the two conditions.
+    ///
+    /// [Gaussian primality]: https://en.wikipedia.org/wiki/Gaussian_integer#Gaussian_primes
+    ///
+    /// # Panics
+    ///
+    /// Panics if both parts are nonzero and the norm does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::{GaussianInt, GaussianPrimeKind};
+    /// # fn main() {
+    /// let z = GaussianInt::new(0, 7);
+    /// assert_eq!(z.gaussian_prime_kind(), Some(GaussianPrimeKind::RationalPrime3Mod4));
+    /// let z = GaussianInt::new(2, 7);
+    /// assert_eq!(z.gaussian_prime_kind(), Some(GaussianPrimeKind::NormIsPrime));
+    /// # }
+    /// ```
+    pub fn gaussian_prime_kind(&self) -> Option<GaussianPrimeKind> {
+        self.gaussian_prime_kind_with(is_prime_u64)
+    }
+
+    /// Like [`gaussian_prime_kind`](GaussianInt::gaussian_prime_kind),
+    /// testing rational primality with `is_prime`.
+    fn gaussian_prime_kind_with(
+        &self,
+        is_prime: impl Fn(u64) -> bool,
+    ) -> Option<GaussianPrimeKind> {
+        // Work with absolute values, so that unsigned types are supported too.
+        let a = abs_u64(self.0.re);
+        let b = abs_u64(self.0.im);
+
+        // No special cases are needed:
+        // zero and the units 1, -1, i, -i fail both conditions, so they are not prime,
+        // 2 = -i(1 + i)² is even, so it fails condition 1,
+        // and 1 + i and its associates pass condition 2 with norm 2.
+        let condition_1 = match (a == 0, b == 0) {
+            (true, false) => b % 4 == 3 && is_prime(b),
+            (false, true) => a % 4 == 3 && is_prime(a),
+            _ => false,
+        };
+        if condition_1 {
+            return Some(GaussianPrimeKind::RationalPrime3Mod4);
+        }
+
+        let condition_2 = match (a == 0, b == 0) {
+            (false, false) => {
+                let n = u64::try_from(self.norm_wide()).expect("norm should fit in a u64");
+                n % 4 != 3 && is_prime(n)
+            }
+            _ => false,
+        };
+        if condition_2 {
+            return Some(GaussianPrimeKind::NormIsPrime);
+        }
+
+        None
+    }
+
+    /// Classifies `self` by the factorization of its norm.
+    ///
+    /// A Gaussian integer with [`NormClass::NormPrime`] is always prime,
+    /// and one with [`NormClass::Composite`] never is.
+    /// One with [`NormClass::NormPrimeSquared`] is prime
+    /// exactly when it is an associate of a rational prime *p* ≡ 3 (mod 4).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the norm does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::{GaussianInt, NormClass};
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, 1).norm_class(), NormClass::NormPrime);
+    /// assert_eq!(GaussianInt::new(3, 0).norm_class(), NormClass::NormPrimeSquared);
+    /// assert_eq!(GaussianInt::new(1, 3).norm_class(), NormClass::Composite);
+    /// # }
+    /// ```
+    pub fn norm_class(&self) -> NormClass {
+        let n = u64::try_from(self.norm_wide()).expect("norm should fit in a u64");
+        let r = isqrt(n);
+        match n {
+            0 => NormClass::Zero,
+            1 => NormClass::Unit,
+            _ if is_prime_u64(n) => NormClass::NormPrime,
+            _ if r * r == n && is_prime_u64(r) => NormClass::NormPrimeSquared,
+            _ => NormClass::Composite,
+        }
+    }
+
+    /// Returns the rational prime *p* that the Gaussian prime `self` lies over,
+    /// or `None` if `self` is not a Gaussian prime.
+    ///
+    /// Every Gaussian prime π divides exactly one rational prime *p*.
+    /// If π is an associate of a rational prime, then *p* = |π|;
+    /// otherwise *p* is the norm of π.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(0, -7).lies_over(), Some(7));
+    /// assert_eq!(GaussianInt::new(2, 7).lies_over(), Some(53));
+    /// assert_eq!(GaussianInt::new(5, 0).lies_over(), None);
+    /// # }
+    /// ```
+    pub fn lies_over(&self) -> Option<u64> {
+        let a = abs_u64(self.0.re);
+        let b = abs_u64(self.0.im);
+        match self.gaussian_prime_kind()? {
+            GaussianPrimeKind::RationalPrime3Mod4 => Some(a.max(b)),
+            GaussianPrimeKind::NormIsPrime => Some(a * a + b * b),
+        }
+    }
+
+    /// Returns `true` if `self` is a Gaussian prime
+    /// lying over an inert rational prime *p* ≡ 3 mod 4,
+    /// which stays prime in the Gaussian integers.
+    pub fn is_over_inert(&self) -> bool {
+        self.lies_over().is_some_and(|p| p % 4 == 3)
+    }
+
+    /// Returns `true` if `self` is a Gaussian prime
+    /// lying over a split rational prime *p* ≡ 1 mod 4,
+    /// which is the product of two non-associate conjugate Gaussian primes.
+    pub fn is_over_split(&self) -> bool {
+        self.lies_over().is_some_and(|p| p % 4 == 1)
+    }
+
+    /// Returns `true` if `self` is a Gaussian prime
+    /// lying over the ramified prime 2 = -*i*(1+*i*)².
+    pub fn is_over_ramified(&self) -> bool {
+        self.lies_over() == Some(2)
+    }
+
+    /// Returns `self` written in base `radix`,
+    /// in the same forms as the [`Display`](std::fmt::Display) implementation,
+    /// such as `a+bi`, `a-i`, or `bi`.
+    ///
+    /// Digits above 9 are the lowercase letters `a` to `z`.
+    /// For a radix above 18, `i` is itself a digit,
+    /// so the result cannot be parsed back with [`from_str_radix`](GaussianInt::from_str_radix).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2 to 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(255, -10);
+    /// assert_eq!(z.to_string_radix(16), "ff-ai");
+    /// assert_eq!(z.to_string_radix(10), z.to_string());
+    /// # }
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2 to 36"
+        );
+        let (re_neg, re) = to_radix(self.0.re, radix);
+        let (im_neg, im) = to_radix(self.0.im, radix);
+        let re = if re_neg { format!("-{re}") } else { re };
+        let im = if im == "1" { String::new() } else { im };
+
+        if self.0.im.is_zero() {
+            return re;
+        }
+        if self.0.re.is_zero() {
+            let sign = if im_neg { "-" } else { "" };
+            return format!("{sign}{im}i");
+        }
+        let sign = if im_neg { "-" } else { "+" };
+        format!("{re}{sign}{im}i")
+    }
+
+    /// Parses a string written in base `radix`, as produced by
+    /// [`to_string_radix`](GaussianInt::to_string_radix).
+    ///
+    /// The accepted forms are the same as for [`FromStr`],
+    /// such as `a+bi`, `a`, or `bi`, with the parts written in base `radix`.
+    /// Returns an error if `radix` is above 18,
+    /// where `i` would be a digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is above 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::<i32>::from_str_radix("101-11i", 2).unwrap();
+    /// assert_eq!(z, GaussianInt::new(5, -3));
+    /// # }
+    /// ```
+    pub fn from_str_radix(
+        s: &str,
+        radix: u32,
+    ) -> Result<Self, ParseComplexError<T::FromStrRadixErr>> {
+        let parse = |s: &str| <Complex<T> as Num>::from_str_radix(s, radix);
+        let err = match parse(s) {
+            Ok(z) => return Ok(Self(z)),
+            Err(err) => err,
+        };
+
+        // `num_complex` reads `e` before a sign as an exponent marker,
+        // but from radix 15 on it is a digit, so split at the sign here instead.
+        let split = s
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '+' || c == '-');
+        if let Some((k, _)) = split {
+            let (head, tail) = s.split_at(k);
+            let head = head.trim_end();
+            let tail = format!("{}{}", &tail[..1], tail[1..].trim_start());
+            let one_imaginary = head.ends_with('i') != tail.ends_with('i');
+            if one_imaginary && !tail[1..].contains(['+', '-']) {
+                if let (Ok(a), Ok(b)) = (parse(head), parse(&tail)) {
+                    return Ok(Self(a + b));
+                }
+            }
+        }
+        Err(err)
     }
 }
 
@@ -97,8 +831,53 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
     /// assert_eq!(z.conj(), GaussianInt::new(5, -4));
     /// # }
     /// ```
-    pub fn conj(&self) -> Self {
-        Self::new(self.0.re, -self.0.im)
+    pub fn conj(&self) -> Self {
+        Self::new(self.0.re, -self.0.im)
+    }
+
+    /// Returns `self` times the conjugate of `other`.
+    ///
+    /// The real part is the [dot product](GaussianInt::dot) of `self` and `other`,
+    /// and the imaginary part is the [cross product](GaussianInt::cross) of `other` and `self`.
+    /// In particular, `z.conj_mul(z)` is the norm of `z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (z, w) = (GaussianInt::new(2, 3), GaussianInt::new(1, -1));
+    /// assert_eq!(z.conj_mul(w), z * w.conj());
+    /// assert_eq!(z.conj_mul(w), GaussianInt::new(-1, 5));
+    /// assert_eq!(z.conj_mul(z), GaussianInt::new(13, 0));
+    /// # }
+    /// ```
+    pub fn conj_mul(&self, other: Self) -> Self {
+        let (a, b) = (self.0.re, self.0.im);
+        let (c, d) = (other.0.re, other.0.im);
+        Self::new(a * c + b * d, b * c - a * d)
+    }
+
+    /// Returns the matrix of multiplication by `self`,
+    /// [[*a*, -*b*], [*b*, *a*]] for `self` = *a* + *b*i, as an array of rows.
+    ///
+    /// Multiplying Gaussian integers corresponds to multiplying their matrices,
+    /// the conjugate to the transpose,
+    /// and the norm to the determinant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(3, 4).to_matrix(), [[3, -4], [4, 3]]);
+    /// // i rotates by 90°.
+    /// assert_eq!(GaussianInt::new(0, 1).to_matrix(), [[0, -1], [1, 0]]);
+    /// # }
+    /// ```
+    pub fn to_matrix(&self) -> [[T; 2]; 2] {
+        let (a, b) = (self.0.re, self.0.im);
+        [[a, -b], [b, a]]
     }
 
     /// Returns the norm.
@@ -113,9 +892,7 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
     /// # }
     /// ```
     pub fn norm(&self) -> usize {
-        let a = self.0.re;
-        let b = self.0.im;
-        (T::pow(a, 2) + T::pow(b, 2)).to_usize().unwrap()
+        self.conj_mul(*self).0.re.to_usize().unwrap()
     }
 
     /// Returns `true` if `self` divides `other`.
@@ -134,6 +911,52 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
         *self != Self::zero() && (other % *self) == Self::zero()
     }
 
+    /// Returns the quotient `other / self` if `self` divides `other`, and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let c1 = GaussianInt::new(5, 0);
+    /// let c2 = GaussianInt::new(1, 2);
+    /// assert_eq!(c2.try_divides(c1), Some(GaussianInt::new(1, -2)));
+    /// assert_eq!(c2.try_divides(GaussianInt::new(2, 1)), None);
+    /// # }
+    /// ```
+    pub fn try_divides(&self, other: Self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let q = other / *self;
+        (q * *self == other).then_some(q)
+    }
+
+    /// Reduces `self` to its canonical residue modulo `modulus`.
+    ///
+    /// Let *N* be the norm of the modulus and *g* the gcd of its parts.
+    /// The result is the unique *x* + *y*i congruent to `self`
+    /// with 0 ≤ *x* < *N*/*g* and 0 ≤ *y* < *g*,
+    /// so two values are congruent exactly when they reduce to the same residue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let m = GaussianInt::new(1, 2);
+    /// assert_eq!(GaussianInt::new(2, 5).reduce_mod(m), GaussianInt::new(2, 0));
+    /// assert_eq!(GaussianInt::new(0, 1).reduce_mod(m), GaussianInt::new(2, 0));
+    /// # }
+    /// ```
+    pub fn reduce_mod(&self, modulus: Self) -> Self {
+        congruence::ResidueBasis::new(modulus).reduce(*self)
+    }
+
     /// Tests whether a Gaussian integer is a rational integer.
     ///
     /// # Example
@@ -150,67 +973,25 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
         self.0.im == T::zero()
     }
 
-    /// Tests for [Gaussian primality].
-    ///
-    /// A Gaussian integer *a* + *b*i is a *Gaussian prime* if and only if either:
+    /// Tests whether a Gaussian integer is a unit times a rational integer.
     ///
-    /// 1. one of *a*, *b* is zero,
-    ///    and the absolute value of the other
-    ///    is a prime number of the form 4*n* + 3
-    ///    (with *n* a nonnegative integer)
-    /// 2. both *a* and *b* are nonzero,
-    ///    and *a*² + *b*² is a prime number
-    ///    (which will not be of the form 4*n* + 3).
+    /// This holds exactly when one of the parts is zero,
+    /// i.e. when the [canonical associate] is rational.
     ///
-    /// [Gaussian primality]: https://en.wikipedia.org/wiki/Gaussian_integer#Gaussian_primes
+    /// [canonical associate]: GaussianInt::canonical_associate
     ///
     /// # Example
     ///
     /// ```
     /// # use gaussiant::GaussianInt;
     /// # fn main() {
-    /// let z = GaussianInt::new(2, 7);
-    /// assert!(z.is_gaussian_prime());
+    /// // 5i = i · 5
+    /// assert!(GaussianInt::new(0, 5).is_associate_of_rational());
+    /// assert!(!GaussianInt::new(2, 1).is_associate_of_rational());
     /// # }
     /// ```
-    pub fn is_gaussian_prime(&self) -> bool {
-        let a = self.0.re;
-        let b = self.0.im;
-
-        // These numbers would cause integer overflow panics below.
-        match (a.abs().to_isize().unwrap(), b.abs().to_isize().unwrap()) {
-            (0, 0) => return false,
-            (1, 1) => return true,
-            (-1, -1) => return true,
-            (2, 0) => return false,
-            (0, 2) => return false,
-            _ => {}
-        }
-
-        let condition_1 = match (a.is_zero(), b.is_zero()) {
-            (true, false) => {
-                let other = b.abs().to_u64().unwrap();
-                primal::is_prime(other) && (other - 3) % 4 == 0
-            }
-            (false, true) => {
-                let other = a.abs().to_u64().unwrap();
-                primal::is_prime(other) && (other - 3) % 4 == 0
-            }
-            _ => false,
-        };
-
-        let condition_2 = match (a.is_zero(), b.is_zero()) {
-            (false, false) => {
-                let a = a.abs().to_u64().unwrap();
-                let b = b.abs().to_u64().unwrap();
-                let sum_of_squares = u64::pow(a, 2) + u64::pow(b, 2);
-                let sum_of_squares_is_4n_plus_3 = (sum_of_squares - 3) % 4 == 0;
-                primal::is_prime(sum_of_squares) && !sum_of_squares_is_4n_plus_3
-            }
-            _ => false,
-        };
-
-        condition_1 || condition_2
+    pub fn is_associate_of_rational(&self) -> bool {
+        self.canonical_associate().is_rational()
     }
 
     /// Returns an array of the units of ℤ\[*i*\], the ring of Gaussian integers.
@@ -247,6 +1028,125 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
         false
     }
 
+    /// Returns the endless sequence `self`, `self`·*i*, `self`·*i*², `self`·*i*³, `self`, ...
+    ///
+    /// Each step rotates a quarter turn counterclockwise,
+    /// so the sequence has period 4 for nonzero `self`
+    /// and its first four elements are the associates of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let orbit: Vec<_> = GaussianInt::new(2, 1).unit_orbit().take(5).collect();
+    /// assert_eq!(
+    ///     orbit,
+    ///     vec![
+    ///         GaussianInt::new(2, 1),
+    ///         GaussianInt::new(-1, 2),
+    ///         GaussianInt::new(-2, -1),
+    ///         GaussianInt::new(1, -2),
+    ///         GaussianInt::new(2, 1),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn unit_orbit(&self) -> impl Iterator<Item = Self> {
+        std::iter::successors(Some(*self), |z| Some(Self::new(-z.0.im, z.0.re)))
+    }
+
+    /// Returns the canonical representative of the associates of `self`.
+    ///
+    /// Every nonzero Gaussian integer has exactly one associate
+    /// *a* + *b*i with *a* > 0 and *b* ≥ 0 (in the first quadrant).
+    /// Zero is its own canonical associate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(-1, -2);
+    /// assert_eq!(z.canonical_associate(), GaussianInt::new(2, -1).canonical_associate());
+    /// assert_eq!(z.canonical_associate(), GaussianInt::new(1, 2));
+    /// # }
+    /// ```
+    pub fn canonical_associate(&self) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+        for u in GaussianInt::units() {
+            let z = *self * u;
+            if z.0.re > T::zero() && z.0.im >= T::zero() {
+                return z;
+            }
+        }
+        unreachable!("every nonzero Gaussian integer has a first-quadrant associate")
+    }
+
+    /// Returns `true` if `self` is a Gaussian prime
+    /// and the [canonical associate] of its associate class.
+    ///
+    /// Each Gaussian prime has four associates,
+    /// and exactly one of them passes this test,
+    /// so it can be used to list primes without repeats.
+    /// Conjugate primes, such as 2+*i* and 2-*i*, are not associates
+    /// unless they lie over 2, so both classes have a representative.
+    ///
+    /// [canonical associate]: GaussianInt::canonical_associate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let primes: Vec<_> = gaussiant::get_g_primes(2)
+    ///     .filter(|p| p.is_prime_representative())
+    ///     .collect();
+    /// assert_eq!(
+    ///     primes,
+    ///     vec![
+    ///         GaussianInt::new(1, 1),
+    ///         GaussianInt::new(1, 2),
+    ///         GaussianInt::new(2, 1),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn is_prime_representative(&self) -> bool {
+        self.is_gaussian_prime() && *self == self.canonical_associate()
+    }
+
+    /// Returns the *primary* associate of `self`,
+    /// or `None` if `self` is [even](GaussianInt::is_even).
+    ///
+    /// A Gaussian integer is primary if it is congruent to 1 modulo (1+*i*)³ = -2+2*i*.
+    /// Every odd Gaussian integer has exactly one primary associate.
+    /// This is the standard normalization in the statement of quartic reciprocity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 3 ≡ 1 mod 2+2i fails, but -3 = 1 - (2+2i)(1-i) is primary.
+    /// assert_eq!(GaussianInt::new(3, 0).primary(), Some(GaussianInt::new(-3, 0)));
+    /// assert_eq!(GaussianInt::new(1, 1).primary(), None);
+    /// # }
+    /// ```
+    pub fn primary(&self) -> Option<Self> {
+        if self.is_even() {
+            return None;
+        }
+        let two = T::one() + T::one();
+        let modulus = Self::new(two, two);
+        GaussianInt::units()
+            .into_iter()
+            .map(|u| *self * u)
+            .find(|z| z.congruent(Self::one(), modulus))
+    }
+
     /// Tests whether a Gaussian integer is "even."
     ///
     /// A Gaussian integer *z* is "even" if *z* ≡ 0 mod 1+*i*.
@@ -290,30 +1190,45 @@ impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
     }
 }
 
-impl<T: PrimInt + Integer + Signed> GaussianInt<T>
-where
-    f64: From<T>,
-{
-    /// Convert to polar form (r, theta), such that
-    /// `self = r * exp(i * theta)`
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use gaussiant::GaussianInt;
-    /// # fn main() {
-    /// let c = GaussianInt::new(0, 1);
-    /// // The polar form of *i* is (1, π/2).
-    /// assert_eq!(c.to_polar(), (1f64, std::f64::consts::PI / 2f64));
-    /// # }
-    /// ```
-    pub fn to_polar(&self) -> (f64, f64) {
-        let a = self.0.re;
-        let b = self.0.im;
-        let a: f64 = a.into();
-        let b: f64 = b.into();
-        Complex::new(a, b).to_polar()
+/// Returns the absolute value of `x` as a `u64`.
+fn abs_u64<T: PrimInt>(x: T) -> u64 {
+    x.to_u64()
+        .or_else(|| x.to_i64().map(i64::unsigned_abs))
+        .unwrap()
+}
+
+/// Small primes tried before the full primality test in [`is_prime_u64`].
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Tests whether `n` is prime.
+///
+/// Most composite numbers have a small factor,
+/// so trial division by a few small primes rejects them
+/// without running the full test.
+fn is_prime_u64(n: u64) -> bool {
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    primal::is_prime(n)
+}
+
+/// Returns whether `x` is negative, and the digits of |`x`| in base `radix`.
+fn to_radix<T: PrimInt>(x: T, radix: u32) -> (bool, String) {
+    let mut n = x
+        .to_u128()
+        .or_else(|| x.to_i128().map(i128::unsigned_abs))
+        .unwrap();
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit((n % radix as u128) as u32, radix).unwrap());
+        n /= radix as u128;
+        if n == 0 {
+            break;
+        }
     }
+    (x < T::zero(), digits.iter().rev().collect())
 }
 
 /// Returns an iterator of all Gaussian integers *a* + *b*i
@@ -368,6 +1283,256 @@ pub fn get_pos_g_primes(n: isize) -> impl Iterator<Item = GaussianInt<isize>> +
     pos_primes.into_iter()
 }
 
+/// Folds `f` over the Gaussian integers *a* + *b*i
+/// with *a* in `re` and *b* in `im`, starting from `init`.
+///
+/// The points are visited in order of increasing *a*,
+/// and for each *a* in order of increasing *b*.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// // The sum of the real parts of the divisors 1, 1 ± 2i, 2 ± i, 5 of 5
+/// let sum = gaussiant::fold_region(1..=5, -5..=5, 0, |sum, z| {
+///     if z.divides(gaussiant::GaussianInt::new(5, 0)) {
+///         sum + z.0.re
+///     } else {
+///         sum
+///     }
+/// });
+/// assert_eq!(sum, 12);
+/// # }
+/// ```
+pub fn fold_region<A, F>(
+    re: RangeInclusive<isize>,
+    im: RangeInclusive<isize>,
+    init: A,
+    mut f: F,
+) -> A
+where
+    F: FnMut(A, GaussianInt<isize>) -> A,
+{
+    let mut acc = init;
+    for a in re {
+        for b in im.clone() {
+            acc = f(acc, GaussianInt::new(a, b));
+        }
+    }
+    acc
+}
+
+/// Counts the Gaussian integers *a* + *b*i with |*a*|, |*b*| ≤ `n` by norm.
+///
+/// Each key is a norm value, and each value the number of points with that norm.
+/// For norms up to `n`², every point of that norm lies in the square,
+/// so the counts there are the sums-of-two-squares function *r*₂.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// let histogram = gaussiant::norm_histogram(3);
+/// // ±1 ± 2i and ±2 ± i
+/// assert_eq!(histogram[&5], 8);
+/// // 3 is not a sum of two squares.
+/// assert_eq!(histogram.get(&3), None);
+/// # }
+/// ```
+pub fn norm_histogram(n: isize) -> BTreeMap<u64, usize> {
+    let mut histogram = BTreeMap::new();
+    for z in get_g_ints(n) {
+        *histogram.entry(z.norm() as u64).or_insert(0) += 1;
+    }
+    histogram
+}
+
+/// Counts the Gaussian integers with norm at most `radius`²,
+/// the lattice points in the closed disk of that radius.
+///
+/// This is Gauss's circle problem:
+/// the count divided by `radius`² tends to π as the radius grows,
+/// with an error of order 1/`radius` or better.
+/// The count is a sum over the rows of the disk,
+/// so it takes time proportional to `radius`.
+///
+/// # Panics
+///
+/// Panics if the count does not fit in a `u64`,
+/// which happens once `radius` exceeds about 2.42 × 10⁹.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// assert_eq!(gaussiant::circle_lattice_count(1), 5);
+/// let r = 1000;
+/// let estimate = gaussiant::circle_lattice_count(r) as f64 / (r * r) as f64;
+/// assert!((estimate - std::f64::consts::PI).abs() < 0.001);
+/// # }
+/// ```
+pub fn circle_lattice_count(radius: u64) -> u64 {
+    let r2 = radius
+        .checked_mul(radius)
+        .expect("radius squared should fit in a u64");
+    let mut count: u64 = 0;
+    for a in 0..=radius {
+        // The column a + bi has |b| ≤ isqrt(r² - a²).
+        let column = 2 * isqrt(r2 - a * a) + 1;
+        count = count
+            .checked_add(if a == 0 { column } else { 2 * column })
+            .expect("lattice point count should fit in a u64");
+    }
+    count
+}
+
+/// Counts the Gaussian primes with norm at most `n`, up to associates.
+///
+/// Each class of four associates is counted once,
+/// so this equals the number of [prime representatives](GaussianInt::is_prime_representative)
+/// with norm at most `n`. Units are not prime and are never counted.
+///
+/// The count comes from the rational primes up to `n`:
+/// 1 + *i* lies over 2, each prime *p* ≡ 1 (mod 4) splits into two classes of norm *p*,
+/// and each prime *p* ≡ 3 (mod 4) stays prime with norm *p*².
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// // 1 + i, 1 + 2i, 2 + i, and 3
+/// assert_eq!(gaussiant::prime_count_up_to_norm(9), 4);
+/// assert_eq!(gaussiant::prime_count_up_to_norm(100), 25);
+/// # }
+/// ```
+pub fn prime_count_up_to_norm(n: u64) -> u64 {
+    if n < 2 {
+        return 0;
+    }
+    let sieve = primal::Sieve::new(n as usize);
+    let mut count = 0;
+    for p in sieve.primes_from(0).take_while(|&p| p as u64 <= n) {
+        let p = p as u64;
+        count += match p % 4 {
+            1 => 2,
+            3 if p <= n / p => 1,
+            3 => 0,
+            _ => 1,
+        };
+    }
+    count
+}
+
+/// Tests for Gaussian primality like [`GaussianInt::is_gaussian_prime`],
+/// looking rational primes up in a shared sieve.
+///
+/// The sieve is built on the first call and kept for the life of the process,
+/// so repeated calls from anywhere in a program avoid testing primality from scratch.
+/// It covers the rational integers up to 2²⁰ and takes well under a megabyte.
+/// Larger norms fall back to the uncached test, and still give the right answer.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// assert!(gaussiant::is_gaussian_prime_cached(GaussianInt::new(2, 7)));
+/// assert!(!gaussiant::is_gaussian_prime_cached(GaussianInt::new(5, 0)));
+/// # }
+/// ```
+pub fn is_gaussian_prime_cached<T: PrimInt + Integer>(z: GaussianInt<T>) -> bool {
+    static SIEVE: OnceLock<primal::Sieve> = OnceLock::new();
+    let sieve = SIEVE.get_or_init(|| primal::Sieve::new(CACHED_SIEVE_LIMIT));
+    z.gaussian_prime_kind_with(|n| sieve_is_prime(sieve, n))
+        .is_some()
+}
+
+/// The largest rational integer in the sieve of [`is_gaussian_prime_cached`].
+const CACHED_SIEVE_LIMIT: usize = 1 << 20;
+
+/// The largest sieve built by [`GaussianInt::are_gaussian_prime`].
+const BATCH_SIEVE_LIMIT: u64 = 1 << 24;
+
+/// Tests whether `n` is prime, looking it up in `sieve` if it is in range.
+fn sieve_is_prime(sieve: &primal::Sieve, n: u64) -> bool {
+    match usize::try_from(n) {
+        Ok(n) if n <= sieve.upper_bound() => sieve.is_prime(n),
+        _ => is_prime_u64(n),
+    }
+}
+
+/// Returns the integer square root of `n`, the largest *r* with *r*² ≤ `n`.
+///
+/// This is exact, unlike rounding a floating-point square root,
+/// so it is suitable for bounding searches by a norm.
+/// It uses Newton's method in `T` without overflowing.
+///
+/// # Panics
+///
+/// Panics if `n` is negative.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// assert_eq!(gaussiant::isqrt(24), 4);
+/// assert_eq!(gaussiant::isqrt(25), 5);
+/// assert_eq!(gaussiant::isqrt(u64::MAX), u32::MAX as u64);
+/// # }
+/// ```
+pub fn isqrt<T: PrimInt + Integer>(n: T) -> T {
+    assert!(n >= T::zero(), "square root of a negative number");
+    let two = T::one() + T::one();
+    if n < two {
+        return n;
+    }
+    // Start above the root, and decrease monotonically.
+    let mut x = n / two + T::one();
+    let mut y = (x + n / x) / two;
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+    x
+}
+
+/// Returns the Gaussian primes *a* + *b*i with *a* > 0, *b* ≥ 0, and norm at most 100,
+/// as `(a, b)` pairs sorted by norm and then by *a*.
+///
+/// Every Gaussian prime of norm at most 100 is associated to exactly one entry.
+///
+/// # Example
+///
+/// ```
+/// # use gaussiant::GaussianInt;
+/// # fn main() {
+/// for &(a, b) in gaussiant::known_small_gaussian_primes() {
+///     assert!(GaussianInt::new(a, b).is_gaussian_prime());
+/// }
+/// # }
+/// ```
+pub fn known_small_gaussian_primes() -> &'static [(i64, i64)] {
+    &KNOWN_SMALL_GAUSSIAN_PRIMES
+}
+
+#[rustfmt::skip]
+const KNOWN_SMALL_GAUSSIAN_PRIMES: [(i64, i64); 25] = [
+    (1, 1),
+    (1, 2), (2, 1),
+    (3, 0),
+    (2, 3), (3, 2),
+    (1, 4), (4, 1),
+    (2, 5), (5, 2),
+    (1, 6), (6, 1),
+    (4, 5), (5, 4),
+    (7, 0),
+    (2, 7), (7, 2),
+    (5, 6), (6, 5),
+    (3, 8), (8, 3),
+    (5, 8), (8, 5),
+    (4, 9), (9, 4),
+];
+
 impl<T: PrimInt + Integer> One for GaussianInt<T> {
     fn one() -> Self {
         GaussianInt::new(T::one(), T::zero())
@@ -384,12 +1549,55 @@ impl<T: PrimInt + Integer> Zero for GaussianInt<T> {
     }
 }
 
+impl<T: PrimInt + Integer> PartialEq<Complex<T>> for GaussianInt<T> {
+    fn eq(&self, other: &Complex<T>) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Compares with the rational integer `other` + 0*i*.
+impl<T: PrimInt + Integer> PartialEq<T> for GaussianInt<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.0.re == *other && self.0.im.is_zero()
+    }
+}
+
 impl<T: PrimInt + Integer> From<Complex<T>> for GaussianInt<T> {
     fn from(z: Complex<T>) -> Self {
         Self(z)
     }
 }
 
+impl<T: PrimInt + Integer> From<T> for GaussianInt<T> {
+    fn from(n: T) -> Self {
+        Self::from_rational(n)
+    }
+}
+
+impl<T: PrimInt + Integer> From<(T, T)> for GaussianInt<T> {
+    fn from((r, i): (T, T)) -> Self {
+        Self::new(r, i)
+    }
+}
+
+impl<T: PrimInt + Integer> From<GaussianInt<T>> for (T, T) {
+    fn from(g: GaussianInt<T>) -> Self {
+        (g.0.re, g.0.im)
+    }
+}
+
+impl<T: PrimInt + Integer> From<[T; 2]> for GaussianInt<T> {
+    fn from([r, i]: [T; 2]) -> Self {
+        Self::new(r, i)
+    }
+}
+
+impl<T: PrimInt + Integer> From<GaussianInt<T>> for [T; 2] {
+    fn from(g: GaussianInt<T>) -> Self {
+        [g.0.re, g.0.im]
+    }
+}
+
 impl<T: PrimInt + Integer> From<GaussianInt<T>> for isize {
     fn from(g: GaussianInt<T>) -> Self {
         g.0.re.to_isize().unwrap()
@@ -421,6 +1629,23 @@ where
     }
 }
 
+// A generic `impl<T> TryFrom<&str>` would overlap the blanket impl from `From<T>`
+// for `T = &str`, so it is implemented for each primitive integer type.
+macro_rules! impl_try_from_str {
+    ($($t:ty),*) => {$(
+        /// Parses like the [`FromStr`] implementation.
+        impl TryFrom<&str> for GaussianInt<$t> {
+            type Error = ParseComplexError<<$t as FromStr>::Err>;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+    )*};
+}
+
+impl_try_from_str!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 impl<T: PrimInt + Integer> std::fmt::Display for GaussianInt<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let one = T::one();
@@ -0,0 +1,51 @@
+use crate::GaussianInt;
+use nalgebra::{Matrix2, Scalar};
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed};
+
+impl<T: PrimInt + Integer + Signed + Scalar> GaussianInt<T> {
+    /// Returns the [matrix of multiplication](GaussianInt::to_matrix) by `self`
+    /// as an [`nalgebra::Matrix2`].
+    ///
+    /// Requires the `nalgebra` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z = GaussianInt::new(3, 4);
+    /// assert_eq!(z.to_nalgebra(), nalgebra::Matrix2::new(3, -4, 4, 3));
+    /// # }
+    /// ```
+    pub fn to_nalgebra(&self) -> Matrix2<T> {
+        let [[a, b], [c, d]] = self.to_matrix();
+        Matrix2::new(a, b, c, d)
+    }
+
+    /// Returns the Gaussian integer whose [matrix](GaussianInt::to_matrix) is `m`,
+    /// or `None` if `m` is not of the form [[*a*, -*b*], [*b*, *a*]].
+    ///
+    /// Requires the `nalgebra` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let m = nalgebra::Matrix2::new(3, -4, 4, 3);
+    /// assert_eq!(GaussianInt::from_nalgebra(&m), Some(GaussianInt::new(3, 4)));
+    /// let m = nalgebra::Matrix2::new(3, 4, 4, 3);
+    /// assert_eq!(GaussianInt::from_nalgebra(&m), None);
+    /// # }
+    /// ```
+    pub fn from_nalgebra(m: &Matrix2<T>) -> Option<Self> {
+        let (a, b) = (m[(0, 0)], m[(1, 0)]);
+        // Compare -b without negating it, which would overflow for `T::MIN`.
+        if m[(1, 1)] == a && T::zero().checked_sub(&b) == Some(m[(0, 1)]) {
+            Some(Self::new(a, b))
+        } else {
+            None
+        }
+    }
+}
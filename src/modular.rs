@@ -0,0 +1,341 @@
+use crate::congruence::ResidueBasis;
+use crate::factor::factor_rational;
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{One, PrimInt, Signed, Zero};
+
+impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
+    /// Returns `self`^`exp` reduced modulo `modulus`.
+    ///
+    /// The result is the canonical residue described in [`reduce_mod`](GaussianInt::reduce_mod).
+    /// Intermediate values are reduced at every step,
+    /// so they stay below the square of the modulus' norm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // N(3 + 2i) = 13, so z¹² ≡ 1 for z not divisible by 3 + 2i.
+    /// let m = GaussianInt::new(3, 2);
+    /// assert_eq!(GaussianInt::new(5, 1).pow_mod(12, m), GaussianInt::new(1, 0));
+    /// # }
+    /// ```
+    pub fn pow_mod(&self, mut exp: u64, modulus: Self) -> Self {
+        let basis = ResidueBasis::new(modulus);
+        let mut base = basis.reduce(*self);
+        let mut result = basis.reduce(Self::one());
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = basis.reduce(result * base);
+            }
+            base = basis.reduce(base * base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative order of `self` modulo `modulus`:
+    /// the smallest *k* > 0 with `self`^*k* ≡ 1.
+    ///
+    /// Returns `None` if `self` is not invertible modulo `modulus`,
+    /// that is, if `self` and `modulus` are not coprime.
+    /// The order divides φ(`modulus`), which is less than *N*(`modulus`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // i has order 4 modulo any modulus with norm greater than 2.
+    /// let m = GaussianInt::new(3, 2);
+    /// assert_eq!(GaussianInt::new(0, 1).multiplicative_order(m), Some(4));
+    /// assert_eq!(GaussianInt::new(6, 4).multiplicative_order(m), None);
+    /// # }
+    /// ```
+    pub fn multiplicative_order(&self, modulus: Self) -> Option<u64> {
+        let basis = ResidueBasis::new(modulus);
+        if Self::gcd(*self, modulus) != Self::one() {
+            return None;
+        }
+        let one = basis.reduce(Self::one());
+        let base = basis.reduce(*self);
+        let mut power = base;
+        let mut k = 1;
+        while power != one {
+            power = basis.reduce(power * base);
+            k += 1;
+        }
+        Some(k)
+    }
+
+    /// Returns the powers `self`¹, `self`², … modulo `modulus`,
+    /// stopping before the first power that repeats an earlier one.
+    ///
+    /// Each power is the canonical residue described in [`reduce_mod`](GaussianInt::reduce_mod).
+    /// If `self` is invertible modulo `modulus`,
+    /// the powers cycle back to `self`,
+    /// and the length of the result is the [`multiplicative_order`](GaussianInt::multiplicative_order).
+    /// Otherwise the powers may settle into a cycle that does not contain `self`,
+    /// and the result ends where that cycle first repeats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // Modulo 3, i ↦ 2i ↦ 2 ↦ 1 ↦ i.
+    /// let m = GaussianInt::new(3, 0);
+    /// assert_eq!(
+    ///     GaussianInt::new(0, 1).power_cycle(m),
+    ///     vec![
+    ///         GaussianInt::new(0, 1),
+    ///         GaussianInt::new(2, 0),
+    ///         GaussianInt::new(0, 2),
+    ///         GaussianInt::new(1, 0),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn power_cycle(&self, modulus: Self) -> Vec<Self> {
+        let basis = ResidueBasis::new(modulus);
+        let base = basis.reduce(*self);
+        let mut powers = vec![];
+        let mut power = base;
+        while !powers.contains(&power) {
+            powers.push(power);
+            power = basis.reduce(power * base);
+        }
+        powers
+    }
+
+    /// Returns a primitive root modulo the Gaussian prime `modulus`:
+    /// a residue whose powers run through every nonzero residue class.
+    ///
+    /// The unit group modulo a Gaussian prime is cyclic of order
+    /// [φ](GaussianInt::euler_phi)(`modulus`) = *N*(`modulus`) - 1,
+    /// so a generator exists.
+    /// This returns the first canonical residue,
+    /// ordered by imaginary and then real part,
+    /// whose [multiplicative order](GaussianInt::multiplicative_order) is that large.
+    ///
+    /// Returns `None` if `modulus` is not a Gaussian prime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let p = GaussianInt::new(3, 2);
+    /// let g = GaussianInt::primitive_root(p).unwrap();
+    /// assert_eq!(g.multiplicative_order(p), Some(12));
+    /// assert_eq!(GaussianInt::primitive_root(GaussianInt::new(5, 0)), None);
+    /// # }
+    /// ```
+    pub fn primitive_root(modulus: Self) -> Option<Self> {
+        if !modulus.is_gaussian_prime() {
+            return None;
+        }
+        let phi = modulus.euler_phi();
+        let basis = ResidueBasis::new(modulus);
+        let one = basis.reduce(Self::one());
+        // g generates the group exactly when g^(φ/q) ≠ 1 for each prime q dividing φ.
+        let prime_divisors: Vec<_> = factor_rational(phi).into_iter().map(|(q, _)| q).collect();
+        basis.residues().into_iter().find(|g| {
+            !g.is_zero()
+                && prime_divisors
+                    .iter()
+                    .all(|q| g.pow_mod(phi / q, modulus) != one)
+        })
+    }
+
+    /// Returns the smallest *x* ≥ 0 with `base`^*x* ≡ `target` modulo `modulus`.
+    ///
+    /// Uses baby-step giant-step over the unit group modulo `modulus`,
+    /// whose order is [φ](GaussianInt::euler_phi)(`modulus`),
+    /// taking about √φ steps and storing about √φ residues.
+    ///
+    /// Returns `None` if there is no solution,
+    /// including whenever `base` or `target` is not invertible modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let m = GaussianInt::new(3, 2);
+    /// let g = GaussianInt::new(1, 1);
+    /// let target = g.pow_mod(7, m);
+    /// assert_eq!(GaussianInt::discrete_log(g, target, m), Some(7));
+    /// # }
+    /// ```
+    pub fn discrete_log(base: Self, target: Self, modulus: Self) -> Option<u64> {
+        let basis = ResidueBasis::new(modulus);
+        if !Self::gcd(base, modulus).is_one() || !Self::gcd(target, modulus).is_one() {
+            return None;
+        }
+        let order = modulus.euler_phi();
+        let steps = crate::isqrt(order - 1) + 1;
+
+        // Baby steps: base^j for 0 ≤ j < steps, keeping the smallest j for each residue.
+        let mut baby = vec![];
+        let mut power = basis.reduce(Self::one());
+        for j in 0..steps {
+            baby.push(((power.0.re, power.0.im), j));
+            power = basis.reduce(power * base);
+        }
+        baby.sort();
+        baby.dedup_by_key(|(key, _)| *key);
+
+        // Giant steps: target · base^(-steps·i), using base^φ ≡ 1.
+        let giant = base.pow_mod(order - steps % order, modulus);
+        let mut gamma = basis.reduce(target);
+        for i in 0..steps {
+            let key = (gamma.0.re, gamma.0.im);
+            if let Ok(k) = baby.binary_search_by_key(&key, |(key, _)| *key) {
+                return Some(i * steps + baby[k].1);
+            }
+            gamma = basis.reduce(gamma * giant);
+        }
+        None
+    }
+
+    /// Returns the quartic residue character (*a*/π)₄.
+    ///
+    /// For an odd Gaussian prime π and *a* not divisible by π,
+    /// this is the unique unit congruent to *a*^((*N*(π) - 1)/4) modulo π.
+    /// It is 1 exactly when *a* is a fourth power modulo π,
+    /// and it is multiplicative in *a*.
+    ///
+    /// Returns `None` if `prime` is not a Gaussian prime,
+    /// if `prime` is an associate of 1+*i*,
+    /// or if `prime` divides `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let p = GaussianInt::new(3, 2);
+    /// // 16 = 2⁴ is a fourth power
+    /// let a = GaussianInt::new(16, 0);
+    /// assert_eq!(GaussianInt::quartic_residue_char(a, p), Some(GaussianInt::new(1, 0)));
+    /// # }
+    /// ```
+    pub fn quartic_residue_char(a: Self, prime: Self) -> Option<Self> {
+        if !prime.is_gaussian_prime() || prime.norm_u64() == 2 || prime.divides(a) {
+            return None;
+        }
+        let r = a.pow_mod((prime.norm_u64() - 1) / 4, prime);
+        GaussianInt::units()
+            .into_iter()
+            .find(|u| u.reduce_mod(prime) == r)
+    }
+
+    /// Returns the canonical residues modulo `modulus`,
+    /// one from each residue class, as described in [`reduce_mod`](GaussianInt::reduce_mod).
+    ///
+    /// There are *N*(`modulus`) of them,
+    /// ordered by imaginary part and then by real part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let residues = GaussianInt::residue_system(GaussianInt::new(2, 0));
+    /// assert_eq!(
+    ///     residues,
+    ///     vec![
+    ///         GaussianInt::new(0, 0),
+    ///         GaussianInt::new(1, 0),
+    ///         GaussianInt::new(0, 1),
+    ///         GaussianInt::new(1, 1),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn residue_system(modulus: Self) -> Vec<Self> {
+        ResidueBasis::new(modulus).residues()
+    }
+
+    /// Returns the addition table of the ring of residues modulo `modulus`.
+    ///
+    /// Entry `[j][k]` is the sum of the `j`th and `k`th elements of
+    /// [`residue_system`](GaussianInt::residue_system), reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let (zero, one) = (GaussianInt::new(0, 0), GaussianInt::new(1, 0));
+    /// let table = GaussianInt::addition_table(GaussianInt::new(1, 1));
+    /// assert_eq!(table, vec![vec![zero, one], vec![one, zero]]);
+    /// # }
+    /// ```
+    pub fn addition_table(modulus: Self) -> Vec<Vec<Self>> {
+        cayley_table(modulus, |a, b| a + b)
+    }
+
+    /// Returns the multiplication table of the ring of residues modulo `modulus`.
+    ///
+    /// Entry `[j][k]` is the product of the `j`th and `k`th elements of
+    /// [`residue_system`](GaussianInt::residue_system), reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // Modulo 2 + i the residues are 0, 1, 2, 3, 4, the field with 5 elements.
+    /// let table = GaussianInt::multiplication_table(GaussianInt::new(2, 1));
+    /// assert_eq!(table[2][3], GaussianInt::new(1, 0));
+    /// # }
+    /// ```
+    pub fn multiplication_table(modulus: Self) -> Vec<Vec<Self>> {
+        cayley_table(modulus, |a, b| a * b)
+    }
+}
+
+/// Tabulates `op` on the canonical residues modulo `modulus`, reducing each entry.
+fn cayley_table<T, F>(modulus: GaussianInt<T>, op: F) -> Vec<Vec<GaussianInt<T>>>
+where
+    T: PrimInt + Integer + Signed,
+    F: Fn(GaussianInt<T>, GaussianInt<T>) -> GaussianInt<T>,
+{
+    let basis = ResidueBasis::new(modulus);
+    let residues = basis.residues();
+    residues
+        .iter()
+        .map(|&a| residues.iter().map(|&b| basis.reduce(op(a, b))).collect())
+        .collect()
+}
@@ -1,6 +1,6 @@
 use crate::GaussianInt;
 use num_integer::Integer;
-use num_traits::{PrimInt, Signed};
+use num_traits::{PrimInt, Signed, Zero};
 
 impl<T: PrimInt + Integer> std::ops::Add for GaussianInt<T> {
     type Output = Self;
@@ -23,17 +23,21 @@ impl<T: PrimInt + Integer> std::ops::Mul for GaussianInt<T> {
     }
 }
 
-impl<T: PrimInt + Integer> std::ops::Div for GaussianInt<T> {
+/// The quotient of [`GaussianInt::div_rem`], rounded to the nearest Gaussian integer.
+impl<T: PrimInt + Integer + Signed> std::ops::Div for GaussianInt<T> {
     type Output = Self;
     fn div(self, other: Self) -> Self::Output {
-        Self::from(self.0 / other.0)
+        self.div_rem(other).0
     }
 }
 
-impl<T: PrimInt + Integer> std::ops::Rem for GaussianInt<T> {
+/// The remainder of [`GaussianInt::div_rem`], with norm at most half that of the divisor.
+///
+/// See [`GaussianInt::rem_with_rounding`] for other conventions.
+impl<T: PrimInt + Integer + Signed> std::ops::Rem for GaussianInt<T> {
     type Output = Self;
     fn rem(self, other: Self) -> Self::Output {
-        Self::from(self.0 % other.0)
+        self.div_rem(other).1
     }
 }
 
@@ -64,14 +68,28 @@ impl<T: PrimInt + Integer> std::ops::MulAssign for GaussianInt<T> {
     }
 }
 
-impl<T: PrimInt + Integer> std::ops::DivAssign for GaussianInt<T> {
+impl<T: PrimInt + Integer + Signed> std::ops::DivAssign for GaussianInt<T> {
     fn div_assign(&mut self, other: Self) {
-        *self = Self::from(self.0 / other.0)
+        *self = *self / other
     }
 }
 
-impl<T: PrimInt + Integer> std::ops::RemAssign for GaussianInt<T> {
+impl<T: PrimInt + Integer + Signed> std::ops::RemAssign for GaussianInt<T> {
     fn rem_assign(&mut self, other: Self) {
-        *self = Self::from(self.0 % other.0)
+        *self = *self % other
+    }
+}
+
+// iterator traits
+
+impl<T: PrimInt + Integer> std::iter::Sum for GaussianInt<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, z| sum + z)
+    }
+}
+
+impl<T: PrimInt + Integer> std::iter::FromIterator<GaussianInt<T>> for GaussianInt<T> {
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().sum()
     }
 }
@@ -0,0 +1,196 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{Num, PrimInt};
+use std::str::FromStr;
+
+/// An error returned when parsing a [`GaussianInt`] with
+/// [`from_str_strict`](GaussianInt::from_str_strict)
+/// or [`parse_many`](GaussianInt::parse_many) fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseGaussianIntError {
+    kind: ParseErrorKind,
+    index: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    /// The string is not of the form `a+bi`, or a part does not fit in the type.
+    Invalid,
+    /// The string is valid, but not how `Display` would write the value.
+    NotCanonical,
+}
+
+impl ParseGaussianIntError {
+    fn invalid() -> Self {
+        Self {
+            kind: ParseErrorKind::Invalid,
+            index: None,
+        }
+    }
+
+    fn not_canonical() -> Self {
+        Self {
+            kind: ParseErrorKind::NotCanonical,
+            index: None,
+        }
+    }
+
+    /// Returns the position in the list of the value that failed to parse,
+    /// counting from zero, if the error came from [`parse_many`](GaussianInt::parse_many).
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+impl std::fmt::Display for ParseGaussianIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParseErrorKind::Invalid => write!(f, "invalid Gaussian integer literal")?,
+            ParseErrorKind::NotCanonical => {
+                write!(f, "Gaussian integer literal is not in canonical form")?
+            }
+        }
+        match self.index {
+            Some(index) => write!(f, " at index {index}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseGaussianIntError {}
+
+impl<T: PrimInt + Integer> GaussianInt<T> {
+    /// Parses a Gaussian integer written exactly as [`Display`](std::fmt::Display) writes it.
+    ///
+    /// The accepted forms are `a`, `bi`, `a+bi`, and `a-bi`,
+    /// with a coefficient of 1 written as just `i`.
+    /// Unlike the lenient [`FromStr`](std::str::FromStr) implementation,
+    /// this rejects whitespace, a leading `+`, leading zeros, and zero parts written out,
+    /// so every value has exactly one accepted string,
+    /// and `GaussianInt::from_str_strict(&z.to_string())` returns `z`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::from_str_strict("3-2i"), Ok(GaussianInt::new(3, -2)));
+    /// assert!(GaussianInt::<i32>::from_str_strict("3 - 2i").is_err());
+    /// assert!(GaussianInt::<i32>::from_str_strict("3+0i").is_err());
+    /// # }
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, ParseGaussianIntError> {
+        let Some(body) = s.strip_suffix('i') else {
+            return Ok(Self::new(parse_part(s, true)?, T::zero()));
+        };
+
+        let split = body
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '+' || c == '-');
+        let (re, sign, im) = match split {
+            Some((k, _)) => (&body[..k], &body[k..=k], &body[k + 1..]),
+            None => match body.strip_prefix('-') {
+                Some(im) => ("0", "-", im),
+                None => ("0", "+", body),
+            },
+        };
+        if im.starts_with(['+', '-']) {
+            return Err(ParseGaussianIntError::invalid());
+        }
+        if im == "0" || im == "1" {
+            return Err(ParseGaussianIntError::not_canonical());
+        }
+
+        let re = parse_part(re, split.is_none())?;
+        let im = if im.is_empty() { "1" } else { im };
+        let im = match sign {
+            "-" => parse_part(&format!("-{im}"), false)?,
+            _ => parse_part(im, false)?,
+        };
+        Ok(Self::new(re, im))
+    }
+
+    /// Parses a JSON array `[re, im]` of two integers,
+    /// as written by [`to_json`](GaussianInt::to_json).
+    ///
+    /// Whitespace is allowed around the brackets, the comma, and the numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::from_json("[2, -3]"), Ok(GaussianInt::new(2, -3)));
+    /// assert!(GaussianInt::<i32>::from_json("[2, -3, 4]").is_err());
+    /// # }
+    /// ```
+    pub fn from_json(s: &str) -> Result<Self, ParseGaussianIntError> {
+        let body = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(ParseGaussianIntError::invalid())?;
+        let (re, im) = body
+            .split_once(',')
+            .ok_or(ParseGaussianIntError::invalid())?;
+        let parse = |part: &str| {
+            parse_part(part.trim(), true).map_err(|_| ParseGaussianIntError::invalid())
+        };
+        Ok(Self::new(parse(re)?, parse(im)?))
+    }
+}
+
+impl<T> GaussianInt<T>
+where
+    T: PrimInt + Integer + FromStr + Num + Clone,
+{
+    /// Parses a list of Gaussian integers separated by whitespace or commas.
+    ///
+    /// Each value is parsed with the [`FromStr`] implementation,
+    /// so it must not contain whitespace itself: write `1+i`, not `1 + i`.
+    /// On failure, the error's [`index`](ParseGaussianIntError::index)
+    /// is the position of the first value that failed to parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let values = GaussianInt::<i32>::parse_many("1+i, -5-4i 3i").unwrap();
+    /// assert_eq!(
+    ///     values,
+    ///     vec![GaussianInt::new(1, 1), GaussianInt::new(-5, -4), GaussianInt::new(0, 3)]
+    /// );
+    ///
+    /// let error = GaussianInt::<i32>::parse_many("1+i, x, 3").unwrap_err();
+    /// assert_eq!(error.index(), Some(1));
+    /// # }
+    /// ```
+    pub fn parse_many(s: &str) -> Result<Vec<Self>, ParseGaussianIntError> {
+        s.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .enumerate()
+            .map(|(index, token)| {
+                token.parse().map_err(|_| ParseGaussianIntError {
+                    index: Some(index),
+                    ..ParseGaussianIntError::invalid()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Parses a decimal integer with no leading zeros or `+` sign.
+///
+/// Zero is rejected unless `allow_zero` is set.
+fn parse_part<T: PrimInt>(s: &str, allow_zero: bool) -> Result<T, ParseGaussianIntError> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(ParseGaussianIntError::invalid());
+    }
+    if digits.starts_with('0') && (digits.len() > 1 || s.len() > 1 || !allow_zero) {
+        return Err(ParseGaussianIntError::not_canonical());
+    }
+    T::from_str_radix(s, 10).map_err(|_| ParseGaussianIntError::invalid())
+}
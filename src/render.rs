@@ -0,0 +1,111 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::PrimInt;
+use std::fmt::Display;
+
+impl<T: PrimInt + Integer> GaussianInt<T> {
+    /// Formats `self` as LaTeX math, such as `2 - 3i`.
+    ///
+    /// Coefficients are cleaned up as in the [`Display`](std::fmt::Display) implementation:
+    /// zero parts are left out, and a coefficient of ±1 on *i* is written as just the sign.
+    /// The binary operator is surrounded by spaces, as LaTeX typesets it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, -3).to_latex(), "2 - 3i");
+    /// assert_eq!(GaussianInt::new(-1, 1).to_latex(), "-1 + i");
+    /// assert_eq!(GaussianInt::new(0, -1).to_latex(), "-i");
+    /// assert_eq!(GaussianInt::new(0, 0).to_latex(), "0");
+    /// # }
+    /// ```
+    pub fn to_latex(&self) -> String
+    where
+        T: Display,
+    {
+        let (re, im) = (self.0.re, self.0.im);
+        let negative = im < T::zero();
+        // Take |im| from its digits, since negating `T::MIN` would overflow.
+        let digits = im.to_string();
+        let imaginary = match digits.trim_start_matches('-') {
+            "1" => "i".to_string(),
+            abs => format!("{abs}i"),
+        };
+        match (re.is_zero(), im.is_zero()) {
+            (_, true) => re.to_string(),
+            (true, _) if negative => format!("-{imaginary}"),
+            (true, _) => imaginary,
+            _ => {
+                let sign = if negative { '-' } else { '+' };
+                format!("{re} {sign} {imaginary}")
+            }
+        }
+    }
+
+    /// Formats `self` as a JSON array `[re, im]`.
+    ///
+    /// [`from_json`](GaussianInt::from_json) parses the result back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// assert_eq!(GaussianInt::new(2, -3).to_json(), "[2, -3]");
+    /// # }
+    /// ```
+    pub fn to_json(&self) -> String
+    where
+        T: Display,
+    {
+        format!("[{}, {}]", self.0.re, self.0.im)
+    }
+}
+
+/// Renders the Gaussian primes *a* + *b*i with |*a*|, |*b*| ≤ `n` as a text grid.
+///
+/// Each prime is marked with `*` and every other point with `.`.
+/// Rows run from *b* = `n` at the top down to *b* = -`n`,
+/// each labeled with its imaginary part,
+/// and a final line labels the columns with their real parts.
+/// Every line ends with a newline.
+///
+/// This is a dependency-free alternative to the `plot` example.
+///
+/// # Example
+///
+/// ```
+/// # fn main() {
+/// let grid = gaussiant::render_primes_ascii(2);
+/// let lines: Vec<&str> = grid.lines().collect();
+/// assert_eq!(lines[0], "  2  .  *  .  *  .");
+/// assert_eq!(lines[1], "  1  *  *  .  *  *");
+/// assert_eq!(lines[2], "  0  .  .  .  .  .");
+/// assert_eq!(lines[5], "    -2 -1  0  1  2");
+/// # }
+/// ```
+pub fn render_primes_ascii(n: isize) -> String {
+    let width = (-n).to_string().len().max(n.to_string().len()) + 1;
+
+    let mut grid = String::new();
+    for b in (-n..=n).rev() {
+        grid.push_str(&format!("{b:>width$}"));
+        for a in -n..=n {
+            let cell = if GaussianInt::new(a, b).is_gaussian_prime() {
+                '*'
+            } else {
+                '.'
+            };
+            grid.push_str(&format!("{cell:>width$}"));
+        }
+        grid.push('\n');
+    }
+    grid.push_str(&" ".repeat(width));
+    for a in -n..=n {
+        grid.push_str(&format!("{a:>width$}"));
+    }
+    grid.push('\n');
+    grid
+}
@@ -0,0 +1,113 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, Signed, Zero};
+
+impl<T: PrimInt + Integer + Signed> GaussianInt<T> {
+    /// Returns the Gaussian prime closest to `self`.
+    ///
+    /// If `self` is prime, it is returned.
+    /// Otherwise the search spirals outward in square rings around `self`
+    /// until no unexamined point can be closer than the best prime found.
+    ///
+    /// When several primes are equally close,
+    /// the one whose offset from `self` has the smallest argument,
+    /// measured counterclockwise from the positive real direction, is returned.
+    /// So ties go first to the quadrant of 1+*i*, then *i*-1, -1-*i*, and 1-*i*.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 5 ± i have norm 26, but 6+i and 4+i are prime.
+    /// let z = GaussianInt::new(5, 0);
+    /// assert_eq!(z.nearest_gaussian_prime(), GaussianInt::new(6, 1));
+    /// # }
+    /// ```
+    pub fn nearest_gaussian_prime(&self) -> Self {
+        if self.is_gaussian_prime() {
+            return *self;
+        }
+
+        let mut best: Option<((u64, u8, u64), Self)> = None;
+        for r in 1_i64.. {
+            for offset in ring(r) {
+                let z =
+                    *self + Self::new(T::from(offset.0.re).unwrap(), T::from(offset.0.im).unwrap());
+                if z.is_gaussian_prime() {
+                    let key = tie_break_key(offset);
+                    match best {
+                        Some((k, _)) if k <= key => {}
+                        _ => best = Some((key, z)),
+                    }
+                }
+            }
+            if let Some(((d2, _, _), z)) = best {
+                // Every point in the next ring is at least r + 1 away.
+                if d2 < ((r + 1) * (r + 1)) as u64 {
+                    return z;
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    /// Returns the first Gaussian prime among `self` + `step`, `self` + 2`step`, ...
+    ///
+    /// Every point of the ray is divisible by *d* = gcd(`self`, `step`),
+    /// so if *d* is not a unit the only possible primes are associates of *d*.
+    /// In that case, once the points have grown past *d* without a prime,
+    /// there can be none further along, and this panics rather than searching forever.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, or if the ray provably contains no prime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// // 1 is a unit and 2 = -i(1 + i)², but 3 is a Gaussian prime.
+    /// let z = GaussianInt::new(0, 0);
+    /// assert_eq!(z.next_prime_in_direction(GaussianInt::new(1, 0)), GaussianInt::new(3, 0));
+    /// # }
+    /// ```
+    pub fn next_prime_in_direction(&self, step: Self) -> Self {
+        assert!(!step.is_zero(), "step must be nonzero");
+        let d = Self::gcd(*self, step);
+        let mut z = *self;
+        let mut last_norm = z.norm_u64();
+        loop {
+            z += step;
+            if z.is_gaussian_prime() {
+                return z;
+            }
+            let norm = z.norm_u64();
+            assert!(
+                d.norm_u64() == 1 || norm <= d.norm_u64() || norm <= last_norm,
+                "no Gaussian prime lies in the direction of the step"
+            );
+            last_norm = norm;
+        }
+    }
+}
+
+/// Returns the offsets *a* + *b*i with max(|*a*|, |*b*|) = `r`.
+fn ring(r: i64) -> impl Iterator<Item = GaussianInt<i64>> {
+    let horizontal = (-r..=r).flat_map(move |a| [GaussianInt::new(a, r), GaussianInt::new(a, -r)]);
+    let vertical = (1 - r..r).flat_map(move |b| [GaussianInt::new(r, b), GaussianInt::new(-r, b)]);
+    horizontal.chain(vertical)
+}
+
+/// Orders offsets by distance, then by argument in [0, 2π).
+fn tie_break_key(offset: GaussianInt<i64>) -> (u64, u8, u64) {
+    let mut z = offset;
+    let mut quadrant = 0;
+    // Rotate by -i into the quadrant with re > 0, im ≥ 0.
+    while !(z.0.re > 0 && z.0.im >= 0) {
+        z = GaussianInt::new(z.0.im, -z.0.re);
+        quadrant += 1;
+    }
+    (offset.norm_u64(), quadrant, z.0.im as u64)
+}
@@ -28,6 +28,78 @@ mod tests {
         assert_eq!(GaussianInt::from(c), g);
     }
 
+    #[test]
+    fn from_tuple_and_array() {
+        let z = GaussianInt::from((3, -2));
+        assert_eq!(z, gaussint!(3, -2));
+        let t: (i32, i32) = z.into();
+        assert_eq!(t, (3, -2));
+
+        let z = GaussianInt::from([3, -2]);
+        assert_eq!(z, gaussint!(3, -2));
+        let a: [i32; 2] = z.into();
+        assert_eq!(a, [3, -2]);
+    }
+
+    #[test]
+    fn from_rational() {
+        assert_eq!(GaussianInt::from(7), gaussint!(7));
+        assert_eq!(GaussianInt::from_rational(-3), gaussint!(-3));
+        let z: GaussianInt<i64> = 5.into();
+        assert_eq!(z, gaussint!(5));
+        assert_eq!(GaussianInt::<u8>::from(200), gaussint!(200, 0));
+
+        for n in -10..=10 {
+            let z = GaussianInt::from(n);
+            assert!(z.is_rational());
+            assert_eq!(z, n);
+            assert_eq!(z, GaussianInt::from_rational(n));
+        }
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(GaussianInt::<i8>::try_new(200i32, 0), None);
+        assert_eq!(GaussianInt::<i8>::try_new(0, 200i32), None);
+        assert_eq!(
+            GaussianInt::<i8>::try_new(100i32, -100),
+            Some(gaussint!(100, -100))
+        );
+        assert_eq!(
+            GaussianInt::<i8>::try_new(-128i64, 127),
+            Some(gaussint!(-128, 127))
+        );
+        assert_eq!(GaussianInt::<i8>::try_new(-129i64, 0), None);
+        assert_eq!(GaussianInt::<u8>::try_new(-1i32, 0), None);
+        assert_eq!(GaussianInt::<i64>::try_new(u64::MAX, 0), None);
+        assert_eq!(GaussianInt::<i64>::try_new(3u8, 4), Some(gaussint!(3, 4)));
+    }
+
+    #[test]
+    fn checked_from_isize() {
+        assert_eq!(GaussianInt::<i8>::from_isize(127), Some(gaussint!(127, 0)));
+        assert_eq!(
+            GaussianInt::<i8>::from_isize(-128),
+            Some(gaussint!(-128, 0))
+        );
+        assert_eq!(GaussianInt::<i8>::from_isize(128), None);
+        assert_eq!(GaussianInt::<u16>::from_isize(-1), None);
+        assert_eq!(
+            GaussianInt::<u16>::from_isize(65535),
+            Some(gaussint!(65535, 0))
+        );
+        assert_eq!(GaussianInt::<i32>::from_isize(isize::MAX), None);
+        assert_eq!(
+            GaussianInt::<i128>::from_isize(isize::MIN),
+            Some(gaussint!(isize::MIN as i128, 0))
+        );
+
+        for n in -50..=50 {
+            let z = GaussianInt::<i64>::from_isize(n).unwrap();
+            assert_eq!(isize::from(z), n);
+        }
+    }
+
     #[test]
     fn addition() {
         let c1 = gaussint!(1, 1);
@@ -132,7 +204,8 @@ mod tests {
         let mut jar = gaussint!(31);
         let piles_of_cookies = gaussint!(4);
         jar %= piles_of_cookies;
-        assert_eq!(jar, gaussint!(3));
+        // 31 / 4 rounds to 8, so the remainder is negative.
+        assert_eq!(jar, gaussint!(-1));
     }
 
     #[test]
@@ -152,6 +225,21 @@ mod tests {
         assert!(five.divides(five));
     }
 
+    #[test]
+    fn norm_divides() {
+        let z = gaussint!(2, 1);
+        assert!(z.norm_divides(gaussint!(5, 0)));
+        assert!(z.divides(gaussint!(5, 0)));
+
+        // N(2+i) = N(2-i) = 5, but 2+i does not divide 2-i.
+        let w = gaussint!(2, -1);
+        assert!(z.norm_divides(w));
+        assert!(!z.divides(w));
+
+        assert!(!gaussint!(1, 1).norm_divides(gaussint!(2, 1)));
+        assert!(!gaussint!(0, 0).norm_divides(gaussint!(2, 1)));
+    }
+
     #[test]
     fn from_isize() {
         let c = gaussint!(5, 0);
@@ -410,6 +498,40 @@ mod tests {
         assert!(!z1.is_associated(z2));
     }
 
+    #[test]
+    fn canonical_associate() {
+        assert_eq!(gaussint!(0, 0).canonical_associate(), gaussint!(0, 0));
+        assert_eq!(gaussint!(-3, 0).canonical_associate(), gaussint!(3, 0));
+        assert_eq!(gaussint!(0, 2).canonical_associate(), gaussint!(2, 0));
+        for u in GaussianInt::units() {
+            let z = gaussint!(2, 1) * u;
+            assert_eq!(z.canonical_associate(), gaussint!(2, 1));
+            assert!(z.is_associated(z.canonical_associate()));
+        }
+    }
+
+    #[test]
+    fn associate_class() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let a = AssociateClass::new(gaussint!(1, 1));
+        let b = AssociateClass::new(gaussint!(1, -1));
+        assert_eq!(a, b);
+
+        let hash = |c: &AssociateClass<isize>| {
+            let mut h = DefaultHasher::new();
+            c.hash(&mut h);
+            h.finish()
+        };
+        assert_eq!(hash(&a), hash(&b));
+
+        assert_ne!(
+            AssociateClass::new(gaussint!(2, 1)),
+            AssociateClass::new(gaussint!(2, -1))
+        );
+    }
+
     #[test]
     #[rustfmt::skip]
     fn display() {
@@ -451,4 +573,2535 @@ mod tests {
         assert_eq!(GaussianInt::from_str("i").expect("oops"),    gaussint!(0, 1));
         assert_eq!(GaussianInt::from_str("-i").expect("oops"),   gaussint!(0,-1));
     }
+
+    #[test]
+    fn reduce_mod() {
+        let moduli = [
+            gaussint!(1, 2),
+            gaussint!(2, 2),
+            gaussint!(3, 0),
+            gaussint!(-2, 4),
+        ];
+        for m in moduli {
+            let n = m.norm_value();
+            let g = num_integer::gcd(m.0.re, m.0.im);
+            for z in get_g_ints(6) {
+                let r = z.reduce_mod(m);
+                assert!(r.congruent(z, m));
+                assert!(0 <= r.0.re && r.0.re < n / g);
+                assert!(0 <= r.0.im && r.0.im < g);
+                assert_eq!((z + m * gaussint!(3, -7)).reduce_mod(m), r);
+            }
+        }
+    }
+
+    #[test]
+    fn congruence_relation() {
+        let a = gaussint!(2, -1);
+        let b = gaussint!(2, 3);
+        let c = gaussint!(5, 4);
+        let n = Congruence::new(gaussint!(1, 1));
+        assert_eq!(n.modulus(), gaussint!(1, 1));
+
+        // reflexivity
+        assert!(n.are_congruent(a, a));
+        // symmetry
+        assert!(n.are_congruent(a, b));
+        assert_eq!(n.are_congruent(a, b), n.are_congruent(b, a));
+        // transitivity
+        assert!(n.are_congruent(b, c));
+        assert!(n.are_congruent(a, c));
+
+        assert!(!n.are_congruent(a, gaussint!(1, 1)));
+        for z in get_g_ints(3) {
+            for w in get_g_ints(3) {
+                assert_eq!(n.are_congruent(z, w), z.congruent(w, n.modulus()));
+            }
+        }
+    }
+
+    #[test]
+    fn gaussian_prime_kind() {
+        assert_eq!(
+            gaussint!(3, 0).gaussian_prime_kind(),
+            Some(GaussianPrimeKind::RationalPrime3Mod4)
+        );
+        assert_eq!(
+            gaussint!(0, -7).gaussian_prime_kind(),
+            Some(GaussianPrimeKind::RationalPrime3Mod4)
+        );
+        assert_eq!(
+            gaussint!(2, 1).gaussian_prime_kind(),
+            Some(GaussianPrimeKind::NormIsPrime)
+        );
+        assert_eq!(
+            gaussint!(1, 1).gaussian_prime_kind(),
+            Some(GaussianPrimeKind::NormIsPrime)
+        );
+        assert_eq!(gaussint!(5, 0).gaussian_prime_kind(), None);
+        assert_eq!(gaussint!(3, 3).gaussian_prime_kind(), None);
+        assert_eq!(gaussint!(0, 0).gaussian_prime_kind(), None);
+    }
+
+    #[test]
+    fn factorize() {
+        // 2 = -i(1 + i)² ramifies
+        assert_eq!(
+            gaussint!(2).factorize(),
+            (gaussint!(0, -1), vec![(gaussint!(1, 1), 2)])
+        );
+        // 5 = -i(1 + 2i)(2 + i) splits
+        assert_eq!(
+            gaussint!(5).factorize(),
+            (
+                gaussint!(0, -1),
+                vec![(gaussint!(1, 2), 1), (gaussint!(2, 1), 1)]
+            )
+        );
+        assert_eq!(
+            gaussint!(3, 4).factorize(),
+            (gaussint!(1), vec![(gaussint!(2, 1), 2)])
+        );
+        // 3 is inert
+        assert_eq!(
+            gaussint!(3).factorize(),
+            (gaussint!(1), vec![(gaussint!(3), 1)])
+        );
+        assert_eq!(
+            gaussint!(0, -9).factorize(),
+            (gaussint!(0, -1), vec![(gaussint!(3), 2)])
+        );
+        assert_eq!(gaussint!(0, 1).factorize(), (gaussint!(0, 1), vec![]));
+
+        // Sorted by norm, then by canonical form.
+        let (_, factors) = gaussint!(0, 30).factorize();
+        let primes: Vec<_> = factors.iter().map(|(p, _)| *p).collect();
+        assert_eq!(
+            primes,
+            vec![
+                gaussint!(1, 1),
+                gaussint!(1, 2),
+                gaussint!(2, 1),
+                gaussint!(3)
+            ]
+        );
+
+        for z in get_g_ints(12).filter(|z| !z.is_zero()) {
+            let (unit, factors) = z.factorize();
+            assert!(GaussianInt::units().contains(&unit));
+            assert_eq!(z.factorize(), (unit, factors.clone()));
+
+            let mut product = unit;
+            for (p, k) in &factors {
+                assert!(p.is_gaussian_prime());
+                assert_eq!(*p, p.canonical_associate());
+                for _ in 0..*k {
+                    product *= *p;
+                }
+            }
+            assert_eq!(product, z);
+
+            let keys: Vec<_> = factors
+                .iter()
+                .map(|(p, _)| (p.norm(), p.0.re, p.0.im))
+                .collect();
+            let mut sorted = keys.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(keys, sorted);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "norm should fit in a u64")]
+    fn factorize_norm_overflow() {
+        gaussint!(i64::MIN, 0).factorize();
+    }
+
+    #[test]
+    fn factor_rational() {
+        // 2⁶⁴ - 1 = 3 · 5 · 17 · 257 · 641 · 65537 · 6700417
+        assert_eq!(
+            crate::factor::factor_rational(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn gaussian_factorizer() {
+        let factorizer = GaussianFactorizer::new(1000);
+        let values = [
+            gaussint!(2),
+            gaussint!(5),
+            gaussint!(7),
+            gaussint!(3, 4),
+            gaussint!(-12, 30),
+            gaussint!(999, 994),
+            gaussint!(60, 0),
+        ];
+        for z in values {
+            assert_eq!(factorizer.factorize(z), z.factorize());
+        }
+        for z in get_g_ints(12).filter(|z| !z.is_zero()) {
+            assert_eq!(factorizer.factorize(z), z.factorize());
+        }
+        assert_eq!(
+            factorizer.factorize(gaussint!(2)).1,
+            vec![(gaussint!(1, 1), 2)]
+        );
+        assert_eq!(
+            factorizer.factorize(gaussint!(13)).1,
+            vec![(gaussint!(2, 3), 1), (gaussint!(3, 2), 1)]
+        );
+        assert_eq!(
+            factorizer.factorize(gaussint!(7)).1,
+            vec![(gaussint!(7), 1)]
+        );
+    }
+
+    #[test]
+    fn nearest_gaussian_prime() {
+        let p = gaussint!(0, 0).nearest_gaussian_prime();
+        assert!(p.is_gaussian_prime());
+        assert_eq!(p.norm(), 2);
+        assert_eq!(p, gaussint!(1, 1));
+
+        assert_eq!(gaussint!(7).nearest_gaussian_prime(), gaussint!(7));
+        for z in get_g_ints(6) {
+            let p = z.nearest_gaussian_prime();
+            assert!(p.is_gaussian_prime());
+            let d = (p - z).norm();
+            for q in get_g_primes(12) {
+                assert!((q - z).norm() >= d);
+            }
+        }
+    }
+
+    #[test]
+    fn is_associate_of_rational() {
+        assert!(gaussint!(0, 5).is_associate_of_rational());
+        assert!(gaussint!(0, 5).is_associated(gaussint!(5)));
+        assert!(gaussint!(-7, 0).is_associate_of_rational());
+        assert!(gaussint!(0, 0).is_associate_of_rational());
+        assert!(!gaussint!(2, 1).is_associate_of_rational());
+        assert!(!gaussint!(1, 1).is_associate_of_rational());
+    }
+
+    #[test]
+    fn wrapping_u8() {
+        let z: GaussianInt<u8> = gaussint!(3, 2);
+        assert_eq!(z.wrapping_conj(), gaussint!(3, 254));
+        assert_eq!(z.wrapping_conj().wrapping_conj(), z);
+        assert_eq!(z.wrapping_neg(), gaussint!(253, 254));
+
+        let w: GaussianInt<u8> = gaussint!(250, 100);
+        assert_eq!(z.wrapping_add(w), gaussint!(253, 102));
+        assert_eq!(w.wrapping_add(w), gaussint!(244, 200));
+        assert_eq!(z.wrapping_sub(w), gaussint!(9, 158));
+
+        // (3 + 2i)(3 - 2i) = 13, even with wrapping
+        assert_eq!(z.wrapping_mul(z.wrapping_conj()), gaussint!(13, 0));
+    }
+
+    #[test]
+    fn known_small_gaussian_primes() {
+        let table = crate::known_small_gaussian_primes();
+        assert_eq!(table.len(), 25);
+        for &(a, b) in table {
+            let p = gaussint!(a, b);
+            assert!(p.is_gaussian_prime());
+            assert_eq!(p, p.canonical_associate());
+            assert!(p.norm() <= 100);
+        }
+
+        // Every prime of norm at most 100 is associated to exactly one entry.
+        for p in get_g_primes(10).filter(|p| p.norm() <= 100) {
+            let matches = table
+                .iter()
+                .filter(|&&(a, b)| p.is_associated(gaussint!(a as isize, b as isize)))
+                .count();
+            assert_eq!(matches, 1);
+        }
+    }
+
+    #[test]
+    fn approximate_rotation() {
+        let z = crate::approximate_rotation(90.0);
+        assert!(z.is_associated(gaussint!(0, 1)));
+        assert_eq!(z, gaussint!(0, 1));
+
+        let z = crate::approximate_rotation(0.0);
+        assert!(z.is_rational() && z.0.re > 0);
+
+        assert_eq!(crate::approximate_rotation(180.0), gaussint!(-1, 0));
+        assert_eq!(crate::approximate_rotation(-90.0), gaussint!(0, -1));
+        assert_eq!(crate::approximate_rotation(360.0), gaussint!(1, 0));
+
+        for degrees in [10.0, 33.3, 100.0, 222.0, 301.5] {
+            let z = crate::approximate_rotation(degrees);
+            let theta = (z.0.im as f64).atan2(z.0.re as f64);
+            let error = (theta.to_degrees() - degrees).rem_euclid(360.0);
+            assert!(error.min(360.0 - error) < 3.0);
+        }
+    }
+
+    #[test]
+    fn consts() {
+        type G = GaussianInt<i32>;
+        assert_eq!(G::I * G::I, -G::ONE);
+        assert_eq!(G::I * G::NEG_I, G::ONE);
+        assert_eq!(G::ZERO, G::zero());
+        assert_eq!(G::ONE, G::one());
+        assert_eq!(G::NEG_ONE, -G::one());
+        assert_eq!([G::ONE, G::NEG_ONE, G::I, G::NEG_I], GaussianInt::units());
+        assert_eq!(GaussianInt::<u8>::I, gaussint!(0, 1));
+    }
+
+    #[test]
+    fn gcd() {
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(5), gaussint!(3, 4)),
+            gaussint!(2, 1)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(5), gaussint!(3, -4)),
+            gaussint!(1, 2)
+        );
+        assert_eq!(GaussianInt::gcd(gaussint!(4), gaussint!(6)), gaussint!(2));
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(0), gaussint!(0, 3)),
+            gaussint!(3)
+        );
+        assert_eq!(GaussianInt::gcd(gaussint!(0), gaussint!(0)), gaussint!(0));
+        for a in get_g_ints(4) {
+            for b in get_g_ints(4) {
+                let g = GaussianInt::gcd(a, b);
+                if g.is_zero() {
+                    assert!(a.is_zero() && b.is_zero());
+                    continue;
+                }
+                assert!(g.divides(a) && g.divides(b));
+                assert_eq!(g, g.canonical_associate());
+                assert_eq!(g, GaussianInt::gcd(b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_fraction() {
+        let (n, d) = GaussianInt::reduce_fraction(gaussint!(2, 2), gaussint!(4, 4));
+        assert!(n.is_associated(gaussint!(1)));
+        assert!(d.is_associated(gaussint!(2)));
+        assert_eq!(GaussianInt::gcd(n, d), gaussint!(1));
+        assert_eq!((n, d), (gaussint!(1), gaussint!(2)));
+
+        // Already coprime: only the units change.
+        let (n, d) = GaussianInt::reduce_fraction(gaussint!(3), gaussint!(0, -2));
+        assert_eq!((n, d), (gaussint!(0, 3), gaussint!(2)));
+
+        let (n, d) = GaussianInt::reduce_fraction(gaussint!(15), gaussint!(6, 3));
+        assert_eq!(n * gaussint!(6, 3), d * gaussint!(15));
+        assert_eq!(d, d.canonical_associate());
+    }
+
+    #[test]
+    fn is_gaussian_prime_unsigned() {
+        assert!(GaussianInt::<u64>::new(3, 20).is_gaussian_prime());
+        assert!(GaussianInt::<u64>::new(1, 1).is_gaussian_prime());
+        assert!(GaussianInt::<u64>::new(7, 0).is_gaussian_prime());
+        assert!(GaussianInt::<usize>::new(0, 3).is_gaussian_prime());
+        assert!(GaussianInt::<u8>::new(2, 1).is_gaussian_prime());
+
+        assert!(!GaussianInt::<u64>::new(2, 0).is_gaussian_prime());
+        assert!(!GaussianInt::<u64>::new(0, 2).is_gaussian_prime());
+        assert!(!GaussianInt::<u64>::new(0, 0).is_gaussian_prime());
+        assert!(!GaussianInt::<u64>::new(5, 0).is_gaussian_prime());
+        assert!(!GaussianInt::<usize>::new(3, 3).is_gaussian_prime());
+
+        for a in 0..=10_i64 {
+            for b in 0..=10_i64 {
+                assert_eq!(
+                    GaussianInt::new(a as u32, b as u32).is_gaussian_prime(),
+                    GaussianInt::new(a, b).is_gaussian_prime()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inner() {
+        let z = gaussint!(3, -2);
+        assert_eq!(*z.inner(), Complex::new(3, -2));
+        assert_eq!(z.into_inner(), Complex::new(3, -2));
+        assert_eq!(GaussianInt::from(z.into_inner()), z);
+        assert_eq!(GaussianInt::from(*z.inner()), z);
+    }
+
+    #[test]
+    fn rational_approx() {
+        let z = Complex::new(0.5, 0.5);
+        let (n, d) = crate::rational_approx(z, 10);
+        let q =
+            Complex::new(n.0.re as f64, n.0.im as f64) / Complex::new(d.0.re as f64, d.0.im as f64);
+        assert!((q - z).norm() < 1e-12);
+        assert!(d.norm() <= 10);
+
+        let z = Complex::new(std::f64::consts::PI, std::f64::consts::E);
+        let mut last_error = f64::INFINITY;
+        for max_norm in [1, 10, 100, 1000] {
+            let (n, d) = crate::rational_approx(z, max_norm);
+            assert!(d.norm() as u64 <= max_norm);
+            let q = Complex::new(n.0.re as f64, n.0.im as f64)
+                / Complex::new(d.0.re as f64, d.0.im as f64);
+            let error = (q - z).norm();
+            assert!(error <= last_error);
+            last_error = error;
+        }
+        assert!(last_error < 0.01);
+    }
+
+    #[test]
+    fn continued_fraction_approx() {
+        let evaluate = |quotients: &[GaussianInt<i64>]| {
+            let mut parts = quotients
+                .iter()
+                .rev()
+                .map(|a| Complex::new(a.0.re as f64, a.0.im as f64));
+            let last = parts.next().unwrap();
+            parts.fold(last, |x, a| a + x.inv())
+        };
+
+        // √2 = 1 + 1/(2 + 1/(2 + ⋯))
+        let sqrt2 = Complex::new(std::f64::consts::SQRT_2, 0.0);
+        let quotients = crate::continued_fraction_approx(sqrt2, 5);
+        assert_eq!(quotients, [1, 2, 2, 2, 2].map(|a| gaussint!(a, 0)));
+        assert!((evaluate(&quotients) - sqrt2).norm() < 1e-3);
+
+        let z = Complex::new(std::f64::consts::PI, std::f64::consts::E);
+        let mut last_error = f64::INFINITY;
+        for depth in [2, 4, 8] {
+            let quotients = crate::continued_fraction_approx(z, depth);
+            assert_eq!(quotients.len(), depth);
+            let error = (evaluate(&quotients) - z).norm();
+            assert!(error < last_error);
+            last_error = error;
+        }
+        assert!(last_error < 1e-6);
+
+        let exact = crate::continued_fraction_approx(Complex::new(0.5, 0.5), 10);
+        assert_eq!(exact, vec![gaussint!(1, 1), gaussint!(-1, 1)]);
+        assert!(crate::continued_fraction_approx(z, 0).is_empty());
+    }
+
+    #[test]
+    fn as_sum_of_two_squares() {
+        use crate::as_sum_of_two_squares;
+        assert_eq!(as_sum_of_two_squares(5), Some((1, 2)));
+        assert_eq!(as_sum_of_two_squares(13), Some((2, 3)));
+        assert_eq!(as_sum_of_two_squares(2), Some((1, 1)));
+        assert_eq!(as_sum_of_two_squares(7), None);
+        assert_eq!(as_sum_of_two_squares(25), None);
+        assert_eq!(as_sum_of_two_squares(1), None);
+
+        for p in (3..2000).filter(|p| primal::is_prime(*p) && p % 4 == 1) {
+            let (a, b) = as_sum_of_two_squares(p).unwrap();
+            assert!(a <= b);
+            assert_eq!(a * a + b * b, p);
+        }
+        let p = 1_000_000_007 * 4 + 1;
+        if primal::is_prime(p) {
+            let (a, b) = as_sum_of_two_squares(p).unwrap();
+            assert_eq!(a * a + b * b, p);
+        }
+        let p = 998_244_353;
+        let (a, b) = as_sum_of_two_squares(p).unwrap();
+        assert_eq!(a * a + b * b, p);
+    }
+
+    #[test]
+    fn checked_norm() {
+        assert_eq!(gaussint!(i32::MAX, i32::MAX).checked_norm(), None);
+        assert_eq!(gaussint!(4, 5).checked_norm(), Some(41));
+        assert_eq!(gaussint!(-4, -5).checked_norm(), Some(41));
+        // 46341² > i32::MAX
+        assert_eq!(gaussint!(46340, 0).checked_norm(), Some(46340 * 46340));
+        assert_eq!(gaussint!(46341, 0).checked_norm(), None);
+        assert_eq!(gaussint!(40000, 40000).checked_norm(), None);
+        assert_eq!(GaussianInt::<u8>::new(11, 11).checked_norm(), Some(242));
+        assert_eq!(GaussianInt::<u8>::new(12, 11).checked_norm(), None);
+    }
+
+    #[test]
+    fn norm_wide() {
+        let n = i64::MAX / 2;
+        let z = gaussint!(n, n);
+        assert_eq!(z.checked_norm(), None);
+        assert!(z.norm_wide() > 0);
+        assert_eq!(z.norm_wide(), 2 * (n as i128) * (n as i128));
+        let m = i64::MAX as i128;
+        assert_eq!(
+            gaussint!(i64::MIN, i64::MAX).norm_wide(),
+            (m + 1) * (m + 1) + m * m
+        );
+        assert_eq!(
+            GaussianInt::new(u32::MAX, u32::MAX).norm_wide(),
+            2 * (u32::MAX as i128).pow(2)
+        );
+
+        for z in get_g_ints(10) {
+            assert_eq!(z.norm_wide(), z.norm() as i128);
+        }
+    }
+
+    #[test]
+    fn partial_eq_complex_and_rational() {
+        let z = gaussint!(3, 4);
+        assert_eq!(z, Complex::new(3, 4));
+        assert_ne!(z, Complex::new(4, 3));
+
+        assert_eq!(z * z.conj(), 25);
+        assert_ne!(z, 3);
+        assert_eq!(gaussint!(-7), -7);
+        assert_ne!(gaussint!(-7, 1), -7);
+        assert_eq!(GaussianInt::<u8>::new(9, 0), 9);
+    }
+
+    #[test]
+    fn iterator_adapters() {
+        let primes: Vec<_> = get_g_ints(5).gaussian_primes().collect();
+        assert_eq!(primes, get_g_primes(5).collect::<Vec<_>>());
+
+        let mut classes: Vec<_> = get_g_ints(5)
+            .gaussian_primes()
+            .canonical_associates()
+            .collect();
+        classes.sort_by_key(|z| (z.norm(), z.0.re));
+        classes.dedup();
+        let table: Vec<_> = crate::known_small_gaussian_primes()
+            .iter()
+            .map(|&(a, b)| gaussint!(a as isize, b as isize))
+            .filter(|p| p.0.re <= 5 && p.0.im <= 5)
+            .collect();
+        assert_eq!(classes, table);
+
+        let unsigned: Vec<GaussianInt<u32>> = (0..4)
+            .map(|a| GaussianInt::new(a, 1))
+            .gaussian_primes()
+            .collect();
+        assert_eq!(unsigned, vec![gaussint!(1, 1), gaussint!(2, 1)]);
+    }
+
+    #[test]
+    fn checked_gcd() {
+        for a in get_g_ints(4) {
+            for b in get_g_ints(4) {
+                assert_eq!(GaussianInt::checked_gcd(a, b), Some(GaussianInt::gcd(a, b)));
+            }
+        }
+
+        // Large coprime inputs overflow i32 in the division step.
+        let a: GaussianInt<i32> = gaussint!(1 << 30, 1);
+        let b: GaussianInt<i32> = gaussint!(3, 1 << 30);
+        assert_eq!(GaussianInt::checked_gcd(a, b), None);
+
+        let a: GaussianInt<i64> = gaussint!(1 << 30, 1);
+        let b: GaussianInt<i64> = gaussint!(3, 1 << 30);
+        assert_eq!(GaussianInt::checked_gcd(a, b), Some(gaussint!(1)));
+    }
+
+    #[test]
+    fn primary() {
+        let modulus = gaussint!(2, 2);
+        for p in get_g_primes(6).filter(|p| p.is_odd()) {
+            let q = p.primary().unwrap();
+            assert!(q.is_associated(p));
+            assert!(q.congruent(gaussint!(1), modulus));
+            assert_eq!(q.primary(), Some(q));
+        }
+        for z in get_g_ints(5).filter(|z| z.is_odd()) {
+            let primaries: Vec<_> = GaussianInt::units()
+                .into_iter()
+                .map(|u| z * u)
+                .filter(|w| w.congruent(gaussint!(1), modulus))
+                .collect();
+            assert_eq!(primaries, vec![z.primary().unwrap()]);
+        }
+        assert_eq!(gaussint!(0).primary(), None);
+        assert_eq!(gaussint!(1, 1).primary(), None);
+        assert_eq!(gaussint!(2, 1).primary(), Some(gaussint!(-1, 2)));
+    }
+
+    #[test]
+    fn pow_mod() {
+        let m = gaussint!(3, 2);
+        for z in get_g_ints(4) {
+            let mut power = gaussint!(1);
+            for k in 0..20 {
+                assert_eq!(z.pow_mod(k, m), power.reduce_mod(m));
+                power = (power * z).reduce_mod(m);
+            }
+        }
+        assert_eq!(gaussint!(2, 1).pow_mod(0, gaussint!(1)), gaussint!(0));
+    }
+
+    #[test]
+    fn quartic_residue_char() {
+        let p = gaussint!(3, 2);
+        let chi = |a| GaussianInt::quartic_residue_char(a, p);
+        for a in get_g_ints(4).filter(|a| !p.divides(*a)) {
+            let c = chi(a).unwrap();
+            assert!(GaussianInt::units().contains(&c));
+            for b in get_g_ints(4).filter(|b| !p.divides(*b)) {
+                assert_eq!(chi(a * b), Some(c * chi(b).unwrap()));
+            }
+        }
+        assert_eq!(chi(gaussint!(16)), Some(gaussint!(1)));
+        assert_eq!(chi(p * gaussint!(2, 5)), None);
+        assert_eq!(
+            GaussianInt::quartic_residue_char(gaussint!(3), gaussint!(1, 1)),
+            None
+        );
+        assert_eq!(
+            GaussianInt::quartic_residue_char(gaussint!(3), gaussint!(5)),
+            None
+        );
+
+        // All four units occur as values of the character.
+        let mut values: Vec<_> = get_g_ints(3).filter_map(chi).collect();
+        values.sort_by_key(|u| (u.0.re, u.0.im));
+        values.dedup();
+        assert_eq!(values.len(), 4);
+    }
+
+    #[test]
+    fn min_and_max_by_norm() {
+        let p = crate::min_by_norm(get_g_primes(5)).unwrap();
+        assert_eq!(p.norm(), 2);
+        assert!(p.is_associated(gaussint!(1, 1)));
+
+        let p = crate::max_by_norm(get_g_primes(5)).unwrap();
+        assert_eq!(p.norm(), 41);
+
+        let p = crate::min_by_norm(get_g_primes(5).filter(|p| p.is_rational())).unwrap();
+        assert_eq!(p.norm(), 9);
+
+        assert_eq!(crate::min_by_norm(Vec::<GaussianInt<i32>>::new()), None);
+        assert_eq!(
+            crate::max_by_norm(vec![gaussint!(1, 2), gaussint!(3), gaussint!(2, -1)]),
+            Some(gaussint!(3))
+        );
+    }
+
+    #[test]
+    fn divisors_with_positive_real() {
+        // See https://projecteuler.net/problem=153
+        let sums: Vec<isize> = (1..=5)
+            .map(|n| GaussianInt::sum_of_divisor_parts(gaussint!(n)))
+            .collect();
+        assert_eq!(sums, vec![1, 5, 4, 13, 12]);
+        assert_eq!(sums.iter().sum::<isize>(), 35);
+
+        for n in 1..=30 {
+            let z = gaussint!(n);
+            let brute: Vec<_> = get_g_ints(n)
+                .filter(|d| d.0.re > 0 && d.divides(z))
+                .collect();
+            let divisors = GaussianInt::divisors_with_positive_real(z);
+            assert_eq!(divisors, brute);
+        }
+
+        let divisors = GaussianInt::divisors_with_positive_real(gaussint!(2));
+        assert_eq!(
+            divisors,
+            vec![
+                gaussint!(1, -1),
+                gaussint!(1),
+                gaussint!(1, 1),
+                gaussint!(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn isqrt() {
+        use crate::isqrt;
+        assert_eq!(isqrt(24), 4);
+        assert_eq!(isqrt(25), 5);
+        assert_eq!(isqrt(26), 5);
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(255_u8), 15);
+        assert_eq!(isqrt(i64::MAX), 3_037_000_499);
+        for n in 0..2000_u32 {
+            let r = isqrt(n);
+            assert!(r * r <= n && n < (r + 1) * (r + 1));
+        }
+    }
+
+    #[test]
+    fn checked_pow() {
+        // Units never overflow.
+        assert_eq!(gaussint!(0, 1).checked_pow(4), Some(gaussint!(1)));
+        assert_eq!(gaussint!(0, -1).checked_pow(1001), Some(gaussint!(0, -1)));
+        assert_eq!(gaussint!(3, 3).checked_pow(50), None);
+        assert_eq!(gaussint!(5, 7).checked_pow(0), Some(gaussint!(1)));
+
+        for z in get_g_ints(4) {
+            let mut power = GaussianInt::one();
+            for k in 0..6 {
+                assert_eq!(z.checked_pow(k), Some(power));
+                power *= z;
+            }
+        }
+
+        // (1 + i)^16 = 256 does not fit in an i8.
+        assert_eq!(
+            GaussianInt::<i8>::new(1, 1).checked_pow(14),
+            Some(gaussint!(0, -128))
+        );
+        assert_eq!(GaussianInt::<i8>::new(1, 1).checked_pow(16), None);
+    }
+
+    #[test]
+    fn neighbors() {
+        let origin: GaussianInt<i32> = GaussianInt::zero();
+        assert_eq!(origin.neighbors(), GaussianInt::units());
+
+        let z = gaussint!(-4, 7);
+        for w in z.neighbors() {
+            assert_eq!((w - z).norm(), 1);
+        }
+
+        let eight = z.neighbors8();
+        assert_eq!(eight[..4], z.neighbors());
+        for (k, w) in eight.iter().enumerate() {
+            assert_eq!((*w - z).norm(), if k < 4 { 1 } else { 2 });
+            assert!(!eight[..k].contains(w));
+        }
+    }
+
+    #[test]
+    fn flood_fill() {
+        let predicate = |z: GaussianInt<i64>| z.is_gaussian_prime() && z.norm() <= 200;
+        let start = gaussint!(1, 1);
+        let cluster = crate::flood_fill(start, predicate, usize::MAX);
+        assert_eq!(cluster[0], start);
+
+        for (k, z) in cluster.iter().enumerate() {
+            assert!(predicate(*z));
+            assert!(!cluster[..k].contains(z));
+            // Connected: each point is adjacent to one found before it.
+            if k > 0 {
+                assert!(z.neighbors().iter().any(|w| cluster[..k].contains(w)));
+            }
+            // Maximal: no neighbor satisfying the predicate was missed.
+            for w in z.neighbors() {
+                assert!(!predicate(w) || cluster.contains(&w));
+            }
+        }
+
+        let near = crate::flood_fill(start, predicate, 1);
+        assert_eq!(
+            near,
+            vec![gaussint!(1, 1), gaussint!(2, 1), gaussint!(1, 2)]
+        );
+        assert!(crate::flood_fill(gaussint!(2, 2), predicate, 10).is_empty());
+    }
+
+    #[test]
+    fn power_cycle() {
+        for m in [
+            gaussint!(3),
+            gaussint!(3, 2),
+            gaussint!(2, 2),
+            gaussint!(5, 1),
+        ] {
+            for z in get_g_ints(5) {
+                let cycle = z.power_cycle(m);
+                for (k, w) in cycle.iter().enumerate() {
+                    assert_eq!(*w, z.pow_mod(k as u64 + 1, m));
+                }
+                match z.multiplicative_order(m) {
+                    Some(order) => {
+                        assert_eq!(cycle.len() as u64, order);
+                        assert_eq!(z.pow_mod(order, m), gaussint!(1).reduce_mod(m));
+                        assert_eq!(z.pow_mod(order + 1, m), cycle[0]);
+                    }
+                    None => assert!(!GaussianInt::gcd(z, m).is_one()),
+                }
+            }
+        }
+
+        // Modulo 1 + 2i, the units have orders 1, 2, 4, 4.
+        let m = gaussint!(1, 2);
+        let orders: Vec<_> = GaussianInt::units()
+            .iter()
+            .map(|u| u.multiplicative_order(m).unwrap())
+            .collect();
+        assert_eq!(orders, vec![1, 2, 4, 4]);
+        // (2 + 2i)² = 8i ≡ 0 mod 2 + 2i
+        assert_eq!(
+            gaussint!(2).power_cycle(gaussint!(2, 2)),
+            vec![gaussint!(2), gaussint!(0)]
+        );
+    }
+
+    #[test]
+    fn lies_over() {
+        let z = gaussint!(3, 0);
+        assert_eq!(z.lies_over(), Some(3));
+        assert!(z.is_over_inert() && !z.is_over_split() && !z.is_over_ramified());
+
+        let z = gaussint!(2, 1);
+        assert_eq!(z.lies_over(), Some(5));
+        assert!(!z.is_over_inert() && z.is_over_split() && !z.is_over_ramified());
+
+        let z = gaussint!(1, 1);
+        assert_eq!(z.lies_over(), Some(2));
+        assert!(!z.is_over_inert() && !z.is_over_split() && z.is_over_ramified());
+
+        for z in get_g_ints(20) {
+            match z.lies_over() {
+                Some(p) => {
+                    let p = GaussianInt::from(p as isize);
+                    assert!(z.divides(p));
+                    let count = [z.is_over_inert(), z.is_over_split(), z.is_over_ramified()]
+                        .iter()
+                        .filter(|b| **b)
+                        .count();
+                    assert_eq!(count, 1);
+                }
+                None => {
+                    assert!(!z.is_gaussian_prime());
+                    assert!(!z.is_over_inert() && !z.is_over_split() && !z.is_over_ramified());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fold_region() {
+        let total = crate::fold_region(-2..=3, 0..=4, 0, |sum, z| sum + z.norm());
+        let mut expected = 0;
+        for a in -2..=3_isize {
+            for b in 0..=4_isize {
+                expected += (a * a + b * b) as usize;
+            }
+        }
+        assert_eq!(total, expected);
+
+        let points = crate::fold_region(0..=1, 5..=6, vec![], |mut v, z| {
+            v.push(z);
+            v
+        });
+        assert_eq!(
+            points,
+            vec![
+                gaussint!(0, 5),
+                gaussint!(0, 6),
+                gaussint!(1, 5),
+                gaussint!(1, 6)
+            ]
+        );
+
+        let primes =
+            crate::fold_region(-4..=4, -4..=4, 0, |n, z| n + z.is_gaussian_prime() as usize);
+        assert_eq!(primes, get_g_primes(4).count());
+    }
+
+    #[test]
+    fn is_prime_representative() {
+        let z = gaussint!(2, 1);
+        let passing: Vec<_> = GaussianInt::units()
+            .iter()
+            .map(|u| z * *u)
+            .filter(|w| w.is_prime_representative())
+            .collect();
+        assert_eq!(passing, vec![gaussint!(2, 1)]);
+        assert!(!gaussint!(1).is_prime_representative());
+        assert!(!gaussint!(5).is_prime_representative());
+
+        // Count associate classes of primes with norm at most n:
+        // two over each p ≡ 1 mod 4, one over 2, and one for each p ≡ 3 mod 4 with p² ≤ n.
+        let n = 400;
+        let mut expected = 0;
+        for p in (2..=n).filter(|p| primal::is_prime(*p as u64)) {
+            expected += match p % 4 {
+                1 => 2,
+                2 => 1,
+                _ if p * p <= n => 1,
+                _ => 0,
+            };
+        }
+        let count = get_g_primes(20)
+            .filter(|p| p.norm() <= n && p.is_prime_representative())
+            .count();
+        assert_eq!(count, expected);
+    }
+
+    #[test]
+    fn lattice_line() {
+        let line: Vec<_> = crate::lattice_line(gaussint!(0), gaussint!(3, 3)).collect();
+        assert_eq!(
+            line,
+            vec![
+                gaussint!(0, 0),
+                gaussint!(1, 1),
+                gaussint!(2, 2),
+                gaussint!(3, 3)
+            ]
+        );
+        let line: Vec<_> = crate::lattice_line(gaussint!(0), gaussint!(0, 2)).collect();
+        assert_eq!(
+            line,
+            vec![gaussint!(0, 0), gaussint!(0, 1), gaussint!(0, 2)]
+        );
+        let line: Vec<_> = crate::lattice_line(gaussint!(5, -1), gaussint!(5, -1)).collect();
+        assert_eq!(line, vec![gaussint!(5, -1)]);
+        // Points are produced lazily, so the start of a huge segment is cheap.
+        let line: Vec<_> = crate::lattice_line(gaussint!(0), gaussint!(1 << 40, 1 << 39))
+            .take(3)
+            .collect();
+        assert_eq!(
+            line,
+            vec![gaussint!(0, 0), gaussint!(1, 1), gaussint!(2, 1)]
+        );
+
+        for a in get_g_ints(3) {
+            for b in get_g_ints(3) {
+                let (a, b) = (
+                    gaussint!(a.0.re as i64, a.0.im as i64),
+                    gaussint!(b.0.re as i64, b.0.im as i64),
+                );
+                let line: Vec<_> = crate::lattice_line(a, b).collect();
+                let d = b - a;
+                assert_eq!(line.len() as i64, d.0.re.abs().max(d.0.im.abs()) + 1);
+                assert_eq!(line[0], a);
+                assert_eq!(*line.last().unwrap(), b);
+                for w in line.windows(2) {
+                    assert!(matches!((w[1] - w[0]).norm(), 1 | 2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mobius() {
+        for u in GaussianInt::<i32>::units() {
+            assert_eq!(u.mobius(), 1);
+        }
+        assert_eq!(gaussint!(1, 1).mobius(), -1);
+        assert_eq!(gaussint!(2).mobius(), 0);
+        assert_eq!(gaussint!(3).mobius(), -1);
+        assert_eq!(gaussint!(15).mobius(), -1);
+        assert_eq!(gaussint!(3, 4).mobius(), 0);
+
+        // Multiplicative on coprime arguments
+        for a in get_g_ints(4).filter(|z| !z.is_zero()) {
+            for b in get_g_ints(4).filter(|z| !z.is_zero()) {
+                if GaussianInt::gcd(a, b).is_one() {
+                    assert_eq!((a * b).mobius(), a.mobius() * b.mobius());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn radical() {
+        let r = gaussint!(2, 0).radical();
+        assert!(r.is_associated(gaussint!(1, 1)));
+        assert_eq!(r, gaussint!(1, 1));
+        assert_eq!(gaussint!(0, -1).radical(), gaussint!(1));
+        assert_eq!(gaussint!(-9).radical(), gaussint!(3));
+
+        for z in get_g_ints(8).filter(|z| !z.is_zero()) {
+            let r = z.radical();
+            assert!(r.divides(z));
+            assert_eq!(r, r.canonical_associate());
+            if z.mobius() != 0 {
+                assert_eq!(r, z.canonical_associate());
+            } else {
+                assert!(r.norm() < z.norm());
+            }
+        }
+    }
+
+    #[test]
+    fn sum_and_collect() {
+        let total: GaussianInt<i32> = GaussianInt::units().into_iter().collect();
+        assert_eq!(total, GaussianInt::zero());
+        let total: GaussianInt<i32> = GaussianInt::units().into_iter().sum();
+        assert_eq!(total, GaussianInt::zero());
+
+        let total: GaussianInt<isize> = GaussianInt::divisors_with_positive_real(gaussint!(5))
+            .into_iter()
+            .collect();
+        assert_eq!(total, gaussint!(12, 0));
+        let empty: GaussianInt<i64> = std::iter::empty().collect();
+        assert_eq!(empty, GaussianInt::zero());
+    }
+
+    #[test]
+    fn to_string_radix() {
+        let z = gaussint!(5, -3);
+        assert_eq!(z.to_string_radix(2), "101-11i");
+        assert_eq!(z.to_string_radix(8), "5-3i");
+        assert_eq!(gaussint!(-64, 9).to_string_radix(8), "-100+11i");
+        assert_eq!(gaussint!(255, 16).to_string_radix(16), "ff+10i");
+        assert_eq!(gaussint!(0, -1).to_string_radix(16), "-i");
+        assert_eq!(gaussint!(0, 31).to_string_radix(16), "1fi");
+        assert_eq!(gaussint!(35, 0).to_string_radix(36), "z");
+        assert_eq!(
+            GaussianInt::<i8>::new(-128, 127).to_string_radix(2),
+            "-10000000+1111111i"
+        );
+        assert_eq!(
+            GaussianInt::<u64>::new(u64::MAX, 1).to_string_radix(16),
+            "ffffffffffffffff+i"
+        );
+
+        for z in get_g_ints(20) {
+            assert_eq!(z.to_string_radix(10), z.to_string());
+            for radix in [2, 8, 16, 18] {
+                let s = z.to_string_radix(radix);
+                assert_eq!(GaussianInt::from_str_radix(&s, radix), Ok(z));
+            }
+        }
+        assert!(GaussianInt::<i32>::from_str_radix("1+i", 19).is_err());
+        assert_eq!(
+            GaussianInt::from_str_radix("-e-14i", 16),
+            Ok(gaussint!(-14, -20))
+        );
+        assert_eq!(
+            GaussianInt::from_str_radix("1e + ei", 16),
+            Ok(gaussint!(30, 14))
+        );
+        assert!(GaussianInt::<i32>::from_str_radix("1e+5", 16).is_err());
+        assert!(GaussianInt::<i32>::from_str_radix("e-1i+1", 16).is_err());
+    }
+
+    #[test]
+    fn square_and_pow() {
+        for z in get_g_ints(30) {
+            assert_eq!(z.square(), z * z);
+        }
+        for z in get_g_ints(4) {
+            let mut power = GaussianInt::one();
+            for k in 0..8 {
+                assert_eq!(z.pow(k), power);
+                power *= z;
+            }
+        }
+        assert_eq!(gaussint!(0, 1).pow(1001), gaussint!(0, 1));
+        assert_eq!(GaussianInt::<u8>::new(3, 0).square(), gaussint!(9, 0));
+    }
+
+    #[test]
+    fn render_primes_ascii() {
+        let n = 10;
+        let grid = crate::render_primes_ascii(n);
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(lines.len() as isize, 2 * n + 2);
+
+        // Returns the cell for a + bi.
+        let cell = |a: isize, b: isize| {
+            let row: Vec<&str> = lines[(n - b) as usize].split_whitespace().collect();
+            assert_eq!(row[0], b.to_string());
+            row[(a + n + 1) as usize]
+        };
+        assert_eq!(cell(0, 0), ".");
+        assert_eq!(cell(1, 1), "*");
+        assert_eq!(cell(3, 0), "*");
+        assert_eq!(cell(0, -7), "*");
+        assert_eq!(cell(5, 0), ".");
+        assert_eq!(cell(-10, 3), "*");
+        for z in get_g_ints(n) {
+            let expected = if z.is_gaussian_prime() { "*" } else { "." };
+            assert_eq!(cell(z.0.re, z.0.im), expected);
+        }
+
+        let labels: Vec<&str> = lines[lines.len() - 1].split_whitespace().collect();
+        assert_eq!(labels.first(), Some(&"-10"));
+        assert_eq!(labels.last(), Some(&"10"));
+    }
+
+    #[test]
+    fn spiral_index() {
+        let spiral: Vec<_> = (0..10).map(GaussianInt::from_spiral_index).collect();
+        assert_eq!(
+            spiral,
+            vec![
+                gaussint!(0, 0),
+                gaussint!(1, 0),
+                gaussint!(1, 1),
+                gaussint!(0, 1),
+                gaussint!(-1, 1),
+                gaussint!(-1, 0),
+                gaussint!(-1, -1),
+                gaussint!(0, -1),
+                gaussint!(1, -1),
+                gaussint!(2, -1),
+            ]
+        );
+
+        for z in get_g_ints(15) {
+            let w = gaussint!(z.0.re as i64, z.0.im as i64);
+            assert_eq!(GaussianInt::from_spiral_index(z.to_spiral_index()), w);
+        }
+        // The first (2n + 1)² indices fill the square |a|, |b| ≤ n.
+        let mut indices: Vec<_> = get_g_ints(15).map(|z| z.to_spiral_index()).collect();
+        indices.sort();
+        assert_eq!(indices, (0..31 * 31).collect::<Vec<_>>());
+        for n in (0..100_000).step_by(997) {
+            let z = GaussianInt::from_spiral_index(n);
+            assert_eq!(z.to_spiral_index(), n);
+            let next = GaussianInt::from_spiral_index(n + 1);
+            assert_eq!((next - z).norm(), 1);
+        }
+
+        let far = gaussint!(-2_000_000_000_i64, 17);
+        assert_eq!(GaussianInt::from_spiral_index(far.to_spiral_index()), far);
+    }
+
+    #[test]
+    fn gcd_canonical_form() {
+        // Raw gcds on an axis are rotated onto the positive real axis.
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(0, 2), gaussint!(0, 4)),
+            gaussint!(2)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(0, -2), gaussint!(0, 4)),
+            gaussint!(2)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(-6), gaussint!(0, -9)),
+            gaussint!(3)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(0, 7), gaussint!(0)),
+            gaussint!(7)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(0, -1), gaussint!(0, 1)),
+            gaussint!(1)
+        );
+        // Off the axes, the result lies in the first quadrant.
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(-2, 2), gaussint!(0, 4)),
+            gaussint!(2, 2)
+        );
+        assert_eq!(
+            GaussianInt::gcd(gaussint!(1, -2), gaussint!(-5)),
+            gaussint!(2, 1)
+        );
+
+        for a in get_g_ints(6) {
+            for b in get_g_ints(3) {
+                let g = GaussianInt::gcd(a, b);
+                if !g.is_zero() {
+                    assert!(g.0.re > 0 && g.0.im >= 0);
+                }
+                for u in GaussianInt::units() {
+                    assert_eq!(GaussianInt::gcd(a * u, b), g);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_str_strict() {
+        assert_eq!(GaussianInt::from_str_strict("3-2i"), Ok(gaussint!(3, -2)));
+        assert_eq!(GaussianInt::from_str_strict("-3+i"), Ok(gaussint!(-3, 1)));
+        assert_eq!(GaussianInt::from_str_strict("-i"), Ok(gaussint!(0, -1)));
+        assert_eq!(GaussianInt::from_str_strict("12i"), Ok(gaussint!(0, 12)));
+        assert_eq!(GaussianInt::from_str_strict("0"), Ok(gaussint!(0)));
+        assert_eq!(GaussianInt::from_str_strict("-40"), Ok(gaussint!(-40)));
+
+        for s in [
+            "3 - 2 i", "3 - 2i", " 3-2i", "3+0i", "0+2i", "0i", "-0", "+3", "03", "3+1i", "1i",
+            "3+-2i", "3--2i", "", "i3", "3+2j", "3+2i+1", "3-", "-",
+        ] {
+            assert!(GaussianInt::<i32>::from_str_strict(s).is_err(), "{s:?}");
+        }
+        assert!(GaussianInt::<i8>::from_str_strict("200").is_err());
+        assert!(GaussianInt::<u8>::from_str_strict("2-i").is_err());
+        assert_eq!(
+            GaussianInt::<i8>::from_str_strict("-128-128i"),
+            Ok(gaussint!(-128, -128))
+        );
+        assert_eq!(
+            GaussianInt::<i32>::from_str_strict("3 - 2 i")
+                .unwrap_err()
+                .to_string(),
+            "invalid Gaussian integer literal"
+        );
+        assert_eq!(
+            GaussianInt::<i32>::from_str_strict("3+0i")
+                .unwrap_err()
+                .to_string(),
+            "Gaussian integer literal is not in canonical form"
+        );
+
+        for z in get_g_ints(12) {
+            assert_eq!(GaussianInt::from_str_strict(&z.to_string()), Ok(z));
+        }
+    }
+
+    #[test]
+    fn dot_and_cross() {
+        let z = gaussint!(2, 3);
+        let w = gaussint!(-1, 5);
+        assert_eq!(z.dot(w), 13);
+        assert_eq!(z.cross(w), 13);
+        assert_eq!(w.cross(z), -13);
+
+        for z in get_g_ints(4) {
+            for w in get_g_ints(4) {
+                let p = z * w.conj();
+                assert_eq!(z.dot(w), p.0.re);
+                assert_eq!(z.cross(w), -p.0.im);
+            }
+        }
+
+        // 1 + i, 3 + 2i, and 5 + 3i lie on a line.
+        let (a, b, c) = (gaussint!(1, 1), gaussint!(3, 2), gaussint!(5, 3));
+        assert_eq!((b - a).cross(c - a), 0);
+        assert_ne!((b - a).cross(gaussint!(5, 4) - a), 0);
+    }
+
+    #[test]
+    fn triangle_area_doubled() {
+        let origin = gaussint!(0);
+        assert!(GaussianInt::are_collinear(
+            gaussint!(1, 1),
+            gaussint!(3, 2),
+            gaussint!(5, 3)
+        ));
+        assert!(GaussianInt::are_collinear(
+            origin,
+            gaussint!(2, -2),
+            gaussint!(-7, 7)
+        ));
+        assert!(GaussianInt::are_collinear(origin, origin, gaussint!(4, 1)));
+        assert!(!GaussianInt::are_collinear(
+            origin,
+            gaussint!(1),
+            gaussint!(0, 1)
+        ));
+
+        // The unit right triangle
+        let area = GaussianInt::triangle_area_doubled(origin, gaussint!(1), gaussint!(0, 1));
+        assert_eq!(area, 1);
+        let area = GaussianInt::triangle_area_doubled(origin, gaussint!(0, 1), gaussint!(1));
+        assert_eq!(area, -1);
+
+        for a in get_g_ints(2) {
+            for b in get_g_ints(2) {
+                let c = gaussint!(3, -1);
+                let area = GaussianInt::triangle_area_doubled(a, b, c);
+                assert_eq!(area, GaussianInt::triangle_area_doubled(b, c, a));
+                assert_eq!(area, -GaussianInt::triangle_area_doubled(b, a, c));
+                // The area is unchanged by translation.
+                let t = gaussint!(-5, 8);
+                assert_eq!(
+                    area,
+                    GaussianInt::triangle_area_doubled(a + t, b + t, c + t)
+                );
+                assert_eq!(area == 0, GaussianInt::are_collinear(a, b, c));
+            }
+        }
+    }
+
+    #[test]
+    fn pick() {
+        // Both legs of length 4 hold 5 lattice points; the hypotenuse holds 5 too.
+        let triangle = [gaussint!(0, 0), gaussint!(4, 0), gaussint!(0, 4)];
+        assert_eq!(GaussianInt::boundary_lattice_points(&triangle), 12);
+        assert_eq!(GaussianInt::interior_lattice_points(&triangle), 3);
+
+        let triangle = [gaussint!(1, 1), gaussint!(6, 3), gaussint!(2, 5)];
+        assert_eq!(GaussianInt::boundary_lattice_points(&triangle), 4);
+        let mut count = 0;
+        let area = GaussianInt::triangle_area_doubled(triangle[0], triangle[1], triangle[2]);
+        for z in get_g_ints(7) {
+            let z = gaussint!(z.0.re as i32, z.0.im as i32);
+            let sides = [
+                GaussianInt::triangle_area_doubled(triangle[0], triangle[1], z),
+                GaussianInt::triangle_area_doubled(triangle[1], triangle[2], z),
+                GaussianInt::triangle_area_doubled(triangle[2], triangle[0], z),
+            ];
+            if sides.iter().all(|s| s.signum() == area.signum()) {
+                count += 1;
+            }
+        }
+        assert_eq!(GaussianInt::interior_lattice_points(&triangle), count);
+
+        // Clockwise order gives the same counts.
+        let mut reversed = triangle;
+        reversed.reverse();
+        assert_eq!(GaussianInt::interior_lattice_points(&reversed), count);
+
+        let square = [
+            gaussint!(-3, -3),
+            gaussint!(3, -3),
+            gaussint!(3, 3),
+            gaussint!(-3, 3),
+        ];
+        assert_eq!(GaussianInt::boundary_lattice_points(&square), 24);
+        assert_eq!(GaussianInt::interior_lattice_points(&square), 25);
+    }
+
+    #[test]
+    fn project_onto_line() {
+        let z = gaussint!(2, 2);
+        assert_eq!(
+            z.project_onto_line(gaussint!(0), gaussint!(1)),
+            gaussint!(2_i64, 0)
+        );
+        assert_eq!(
+            z.project_onto_line(gaussint!(7, 0), gaussint!(7, 1)),
+            gaussint!(7_i64, 2)
+        );
+        assert_eq!(
+            z.project_onto_line(gaussint!(1, 1), gaussint!(-4, -4)),
+            gaussint!(2_i64, 2)
+        );
+        // The projection of 1 onto the line through 0 and 1 + 2i is 1/5 + 2/5 i.
+        assert_eq!(
+            gaussint!(1).project_onto_line(gaussint!(0), gaussint!(1, 2)),
+            gaussint!(0_i64, 0)
+        );
+        // 5/2 + 5/2 i rounds up to 3 + 3i.
+        assert_eq!(
+            gaussint!(5, 0).project_onto_line(gaussint!(0), gaussint!(1, 1)),
+            gaussint!(3_i64, 3)
+        );
+        assert_eq!(
+            gaussint!(-5, 0).project_onto_line(gaussint!(0), gaussint!(1, 1)),
+            gaussint!(-2_i64, -2)
+        );
+
+        // Points on the line project to themselves.
+        for t in -5..=5 {
+            let a = gaussint!(1, -2);
+            let d = gaussint!(3, 1);
+            let on_line = a + d * gaussint!(t);
+            let p = on_line.project_onto_line(a, a + d);
+            assert_eq!(p, gaussint!(on_line.0.re as i64, on_line.0.im as i64));
+        }
+
+        // 2^50 projects to just under 2^50 + 1024i, where a * den + num * d exceeds an i128.
+        let d = gaussint!(1_i64 << 40, 1);
+        assert_eq!(
+            gaussint!(1_i64 << 50).project_onto_line(gaussint!(0), d),
+            gaussint!(1_i64 << 50, 1024)
+        );
+        let a = gaussint!(-(1_i64 << 50), 1_i64 << 50);
+        assert_eq!(
+            (a + gaussint!(1_i64 << 50)).project_onto_line(a, a + d),
+            a + gaussint!(1_i64 << 50, 1024)
+        );
+    }
+
+    #[test]
+    fn sort_by_argument() {
+        let mut units = GaussianInt::<i64>::units();
+        crate::sort_by_argument(&mut units);
+        assert_eq!(
+            units,
+            [
+                gaussint!(1),
+                gaussint!(0, 1),
+                gaussint!(-1),
+                gaussint!(0, -1)
+            ]
+        );
+
+        let mut points: Vec<GaussianInt<i64>> = get_g_ints(6)
+            .map(|z| gaussint!(z.0.re as i64, z.0.im as i64))
+            .collect();
+        crate::sort_by_argument(&mut points);
+        assert_eq!(points[0], gaussint!(0));
+        let angle = |z: &GaussianInt<i64>| {
+            (z.0.im as f64)
+                .atan2(z.0.re as f64)
+                .rem_euclid(std::f64::consts::TAU)
+        };
+        for w in points[1..].windows(2) {
+            assert!(angle(&w[0]) <= angle(&w[1]));
+        }
+
+        // Stable for points with equal arguments
+        let mut points = [
+            gaussint!(2, 2),
+            gaussint!(0, 0),
+            gaussint!(1, 1),
+            gaussint!(i64::MAX, 0),
+            gaussint!(1, 0),
+        ];
+        crate::sort_by_argument(&mut points);
+        assert_eq!(
+            points,
+            [
+                gaussint!(0, 0),
+                gaussint!(i64::MAX, 0),
+                gaussint!(1, 0),
+                gaussint!(2, 2),
+                gaussint!(1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_gcd() {
+        for a in get_g_ints(5) {
+            for b in get_g_ints(5) {
+                let (g, x, y) = GaussianInt::extended_gcd(a, b);
+                assert_eq!(g, GaussianInt::gcd(a, b));
+                assert!(GaussianInt::verify_bezout(a, b, g, x, y));
+                assert!(!GaussianInt::verify_bezout(a, b, g + gaussint!(1), x, y));
+            }
+        }
+
+        let (a, b) = (gaussint!(4, 1), gaussint!(1, 8));
+        let (g, x, y) = GaussianInt::extended_gcd(a, b);
+        assert_eq!(g, gaussint!(1));
+        assert_eq!(a * x + b * y, gaussint!(1));
+        assert_eq!(
+            GaussianInt::extended_gcd(gaussint!(0), gaussint!(0)).0,
+            gaussint!(0)
+        );
+    }
+
+    #[test]
+    fn euler_phi() {
+        assert_eq!(gaussint!(0, 1).euler_phi(), 1);
+        assert_eq!(gaussint!(1, 1).euler_phi(), 1);
+        assert_eq!(gaussint!(3).euler_phi(), 8);
+        assert_eq!(gaussint!(5).euler_phi(), 16);
+        for m in get_g_ints(4).filter(|z| !z.is_zero()) {
+            let basis = crate::congruence::ResidueBasis::new(m);
+            let coprime = basis
+                .residues()
+                .into_iter()
+                .filter(|z| GaussianInt::gcd(*z, m).is_one())
+                .count();
+            assert_eq!(m.euler_phi(), coprime as u64);
+        }
+    }
+
+    #[test]
+    fn primitive_root() {
+        for p in [
+            gaussint!(1, 1),
+            gaussint!(2, 1),
+            gaussint!(3),
+            gaussint!(3, 2),
+            gaussint!(7),
+            gaussint!(5, 4),
+        ] {
+            let g = GaussianInt::primitive_root(p).unwrap();
+            let cycle = g.power_cycle(p);
+            assert_eq!(cycle.len() as u64, p.norm() as u64 - 1);
+            let basis = crate::congruence::ResidueBasis::new(p);
+            let mut units: Vec<_> = basis
+                .residues()
+                .into_iter()
+                .filter(|z| !z.is_zero())
+                .collect();
+            let mut cycle = cycle;
+            units.sort_by_key(|z| (z.0.re, z.0.im));
+            cycle.sort_by_key(|z| (z.0.re, z.0.im));
+            assert_eq!(cycle, units);
+        }
+        assert_eq!(GaussianInt::primitive_root(gaussint!(5)), None);
+        assert_eq!(GaussianInt::primitive_root(gaussint!(2)), None);
+        assert_eq!(GaussianInt::primitive_root(gaussint!(1)), None);
+    }
+
+    #[test]
+    fn discrete_log() {
+        for p in [
+            gaussint!(2, 1),
+            gaussint!(3),
+            gaussint!(3, 2),
+            gaussint!(7),
+            gaussint!(5, 4),
+            gaussint!(11),
+        ] {
+            let g = GaussianInt::primitive_root(p).unwrap();
+            let order = p.euler_phi();
+            for k in 0..order {
+                let target = g.pow_mod(k, p);
+                assert_eq!(GaussianInt::discrete_log(g, target, p), Some(k));
+            }
+            assert_eq!(GaussianInt::discrete_log(g, p * gaussint!(2), p), None);
+        }
+
+        // Modulo 3, i only generates the subgroup {1, i, -1, -i}.
+        let m = gaussint!(3);
+        assert_eq!(
+            GaussianInt::discrete_log(gaussint!(0, 1), gaussint!(-1), m),
+            Some(2)
+        );
+        assert_eq!(
+            GaussianInt::discrete_log(gaussint!(0, 1), gaussint!(1, 1), m),
+            None
+        );
+        // Composite moduli work too.
+        let m = gaussint!(6, 3);
+        for base in get_g_ints(3).filter(|z| GaussianInt::gcd(*z, m).is_one()) {
+            for k in 0..20 {
+                let x = GaussianInt::discrete_log(base, base.pow_mod(k, m), m).unwrap();
+                assert!(x <= k);
+                assert_eq!(base.pow_mod(x, m), base.pow_mod(k, m));
+            }
+        }
+    }
+
+    #[test]
+    fn norm_histogram() {
+        let n = 10;
+        let histogram = crate::norm_histogram(n);
+        assert_eq!(histogram[&0], 1);
+        assert_eq!(histogram[&1], 4);
+        assert_eq!(histogram[&2], 4);
+        assert_eq!(histogram.values().sum::<usize>(), 21 * 21);
+        assert_eq!(histogram.keys().last(), Some(&200));
+
+        // r₂(m) for m ≤ n²
+        for m in 0..=(n * n) as u64 {
+            let r2 = (-n..=n)
+                .flat_map(|a| (-n..=n).map(move |b| (a * a + b * b) as u64))
+                .filter(|&norm| norm == m)
+                .count();
+            assert_eq!(histogram.get(&m).copied().unwrap_or(0), r2);
+        }
+        assert_eq!(histogram[&25], 12);
+    }
+
+    #[test]
+    fn gaussian_prime_small_factor_precheck() {
+        // The definition, testing primality of the norm directly.
+        let reference = |z: GaussianInt<isize>| {
+            let (a, b) = (z.0.re.unsigned_abs() as u64, z.0.im.unsigned_abs() as u64);
+            match (a, b) {
+                (0, 0) => false,
+                (0, p) | (p, 0) => p % 4 == 3 && primal::is_prime(p),
+                _ => primal::is_prime(a * a + b * b),
+            }
+        };
+        for z in get_g_ints(60) {
+            assert_eq!(z.is_gaussian_prime(), reference(z), "{z}");
+        }
+        for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43] {
+            assert_eq!(gaussint!(p).is_gaussian_prime(), p % 4 == 3);
+        }
+        // Norms that are squares of small primes, or have no small factor
+        assert!(!gaussint!(0, 37 * 37).is_gaussian_prime());
+        assert!(!gaussint!(41 * 43).is_gaussian_prime());
+        let z = gaussint!(1_000_000_000_i64, 1_000_003);
+        assert_eq!(z.is_gaussian_prime(), primal::is_prime(z.norm_u64()));
+    }
+
+    #[test]
+    fn to_matrix() {
+        let mul = |m: [[isize; 2]; 2], n: [[isize; 2]; 2]| {
+            let mut p = [[0; 2]; 2];
+            for i in 0..2 {
+                for j in 0..2 {
+                    p[i][j] = m[i][0] * n[0][j] + m[i][1] * n[1][j];
+                }
+            }
+            p
+        };
+        for z in get_g_ints(4) {
+            let m = z.to_matrix();
+            assert_eq!(m[0][0] * m[1][1] - m[0][1] * m[1][0], z.norm() as isize);
+            assert_eq!(
+                z.conj().to_matrix(),
+                [[m[0][0], m[1][0]], [m[0][1], m[1][1]]]
+            );
+            for w in get_g_ints(3) {
+                assert_eq!((z * w).to_matrix(), mul(m, w.to_matrix()));
+            }
+        }
+        // Acting on the column vector (re, im) multiplies by z.
+        let (z, w) = (gaussint!(2, -3), gaussint!(5, 1));
+        let m = z.to_matrix();
+        let v = [w.0.re, w.0.im];
+        let product = [
+            m[0][0] * v[0] + m[0][1] * v[1],
+            m[1][0] * v[0] + m[1][1] * v[1],
+        ];
+        assert_eq!(GaussianInt::from(product), z * w);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_round_trip() {
+        for z in get_g_ints(5) {
+            let m = z.to_nalgebra();
+            assert_eq!(GaussianInt::from_nalgebra(&m), Some(z));
+            for w in get_g_ints(2) {
+                assert_eq!((z * w).to_nalgebra(), m * w.to_nalgebra());
+            }
+        }
+        let m = nalgebra::Matrix2::new(1, 2, 3, 4);
+        assert_eq!(GaussianInt::from_nalgebra(&m), None);
+        let m = nalgebra::Matrix2::new(1, 2, 2, 1);
+        assert_eq!(GaussianInt::from_nalgebra(&m), None);
+        // -i32::MIN does not fit in an i32, so no Gaussian integer has this matrix.
+        let m = nalgebra::Matrix2::new(0, 0, i32::MIN, 0);
+        assert_eq!(GaussianInt::from_nalgebra(&m), None);
+    }
+
+    #[test]
+    fn checked_congruent() {
+        for a in get_g_ints(3) {
+            for b in get_g_ints(3) {
+                for m in get_g_ints(2).filter(|m| !m.is_zero()) {
+                    assert_eq!(a.checked_congruent(b, m), Some(a.congruent(b, m)));
+                }
+                assert_eq!(a.checked_congruent(b, gaussint!(0)), None);
+            }
+        }
+
+        // The product with the conjugate of the modulus overflows i32.
+        let a: GaussianInt<i32> = gaussint!(1 << 30, 3);
+        let b = gaussint!(-(1 << 30), 3);
+        let m = gaussint!(1 << 16, 1 << 16);
+        assert_eq!(a.checked_congruent(b, m), None);
+        // Widening gives the answer: a - b = 2³¹ = (1 + i)⁶² · unit, and m = (1 + i)³³ · unit.
+        let wide = |z: GaussianInt<i32>| gaussint!(z.0.re as i64, z.0.im as i64);
+        assert_eq!(wide(a).checked_congruent(wide(b), wide(m)), Some(true));
+        assert_eq!(
+            wide(a).checked_congruent(wide(b) + gaussint!(1), wide(m)),
+            Some(false)
+        );
+        assert_eq!(
+            gaussint!(i32::MIN, 0).checked_congruent(gaussint!(1), gaussint!(3)),
+            None
+        );
+    }
+
+    #[test]
+    fn try_divides() {
+        let five = gaussint!(5, 0);
+        assert_eq!(gaussint!(1, 0).try_divides(five), Some(five));
+        assert_eq!(gaussint!(1, 2).try_divides(five), Some(gaussint!(1, -2)));
+        assert_eq!(gaussint!(1, -2).try_divides(five), Some(gaussint!(1, 2)));
+        assert_eq!(gaussint!(2, 1).try_divides(five), Some(gaussint!(2, -1)));
+        assert_eq!(gaussint!(2, -1).try_divides(five), Some(gaussint!(2, 1)));
+        assert_eq!(five.try_divides(five), Some(gaussint!(1)));
+        assert_eq!(gaussint!(2, 1).try_divides(gaussint!(2, -1)), None);
+        assert_eq!(gaussint!(0, 0).try_divides(five), None);
+
+        for a in get_g_ints(4) {
+            for b in get_g_ints(4) {
+                assert_eq!(a.try_divides(b).is_some(), a.divides(b));
+                if let Some(q) = a.try_divides(b) {
+                    assert_eq!(q * a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prime_count_up_to_norm() {
+        assert_eq!(crate::prime_count_up_to_norm(0), 0);
+        assert_eq!(crate::prime_count_up_to_norm(1), 0);
+        assert_eq!(crate::prime_count_up_to_norm(2), 1);
+        assert_eq!(
+            crate::prime_count_up_to_norm(100),
+            crate::known_small_gaussian_primes().len() as u64
+        );
+
+        // Every norm up to 400 fits in the box |a|, |b| ≤ 20.
+        for n in 0..=400 {
+            let brute = get_g_ints(20)
+                .filter(|z| z.is_prime_representative() && z.norm() as u64 <= n)
+                .count() as u64;
+            assert_eq!(crate::prime_count_up_to_norm(n), brute, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn unit_orbit() {
+        for z in get_g_ints(3).filter(|z| !z.is_zero()) {
+            let orbit: Vec<_> = z.unit_orbit().take(5).collect();
+            for u in GaussianInt::units() {
+                assert!(orbit[..4].contains(&(z * u)));
+            }
+            assert!(orbit[..4].iter().all(|w| w.is_associated(z)));
+            assert_eq!(orbit[4], orbit[0]);
+            assert_eq!(orbit[1], z * gaussint!(0, 1));
+        }
+        assert!(gaussint!(0, 0).unit_orbit().take(8).all(|z| z.is_zero()));
+    }
+
+    #[test]
+    fn round_div() {
+        for a in get_g_ints(6) {
+            for b in get_g_ints(4).filter(|b| !b.is_zero()) {
+                let q = a.round_div(b);
+                let r = a.rem_euclid(b);
+                assert_eq!(q * b + r, a);
+                assert!(2 * r.norm() <= b.norm(), "{a} rem {b} = {r}");
+            }
+        }
+        assert_eq!(gaussint!(7, 2).round_div(gaussint!(2, 1)), gaussint!(3, -1));
+        // Halves round up.
+        assert_eq!(gaussint!(1, 1).round_div(gaussint!(2)), gaussint!(1, 1));
+        assert_eq!(gaussint!(-1, -1).round_div(gaussint!(2)), gaussint!(0, 0));
+    }
+
+    #[test]
+    fn reduce_basis() {
+        let bases = [
+            (gaussint!(3, 1), gaussint!(7, 3)),
+            (gaussint!(1, 0), gaussint!(100, 1)),
+            (gaussint!(17, 5), gaussint!(-12, -3)),
+            (gaussint!(0, 5), gaussint!(3, 0)),
+            (gaussint!(41, 29), gaussint!(29, 20)),
+        ];
+        for (a, b) in bases {
+            let (v1, v2) = GaussianInt::reduce_basis(a, b);
+            assert!(v1.norm() <= v2.norm());
+            assert!(v1.norm() <= a.norm().min(b.norm()));
+            assert!(v2.norm() <= a.norm().max(b.norm()));
+            assert!(2 * v1.dot(v2).abs() <= v1.norm() as isize);
+            // Same determinant, and each input is an integer combination of the output.
+            assert_eq!(v1.cross(v2).abs(), a.cross(b).abs());
+            let det = v1.cross(v2);
+            for z in [a, b] {
+                assert_eq!(v1.cross(z) % det, 0);
+                assert_eq!(z.cross(v2) % det, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_vector() {
+        let (a, b) = (gaussint!(41, 29), gaussint!(70, 49));
+        let v = GaussianInt::shortest_vector(a, b);
+        let mut brute = usize::MAX;
+        for m in -50..=50 {
+            for n in -50..=50 {
+                let z = gaussint!(m * 41 + n * 70, m * 29 + n * 49);
+                if !z.is_zero() {
+                    brute = brute.min(z.norm());
+                }
+            }
+        }
+        assert_eq!(v.norm(), brute);
+        assert_eq!(brute, 18);
+    }
+
+    #[test]
+    fn as_f64_pair() {
+        let z: GaussianInt<i64> = gaussint!(123_456_789, -987_654_321);
+        assert_eq!(z.as_f64_pair(), (123_456_789.0, -987_654_321.0));
+        let (x, y) = z.as_f64_pair();
+        assert_eq!((x as i64, y as i64), (z.0.re, z.0.im));
+        assert_eq!(gaussint!(7u8, 0).as_f64_pair(), (7.0, 0.0));
+        // `to_polar` no longer needs `f64: From<T>`.
+        assert_eq!(
+            gaussint!(0i64, 2).to_polar(),
+            (2.0, std::f64::consts::FRAC_PI_2)
+        );
+        // Nor does it need signed parts.
+        assert_eq!(
+            gaussint!(0u32, 3).to_polar(),
+            (3.0, std::f64::consts::FRAC_PI_2)
+        );
+    }
+
+    #[test]
+    fn conj_mul() {
+        for z in get_g_ints(4) {
+            for w in get_g_ints(4) {
+                let p = z.conj_mul(w);
+                assert_eq!(p, z * w.conj());
+                assert_eq!(p.0.re, z.dot(w));
+                assert_eq!(p.0.im, w.cross(z));
+            }
+            assert_eq!(z.conj_mul(z), gaussint!(z.norm() as isize));
+        }
+    }
+
+    #[test]
+    fn is_gaussian_prime_cached() {
+        for z in get_g_ints(40) {
+            assert_eq!(
+                crate::is_gaussian_prime_cached(z),
+                z.is_gaussian_prime(),
+                "{z}"
+            );
+        }
+        // Beyond the sieve.
+        for z in [
+            gaussint!(1_000_003i64, 0),
+            gaussint!(1024, 1031),
+            gaussint!(1_000_000_007, 2),
+        ] {
+            assert_eq!(
+                crate::is_gaussian_prime_cached(z),
+                z.is_gaussian_prime(),
+                "{z}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_latex() {
+        assert_eq!(gaussint!(0, 0).to_latex(), "0");
+        assert_eq!(gaussint!(7, 0).to_latex(), "7");
+        assert_eq!(gaussint!(-7, 0).to_latex(), "-7");
+        assert_eq!(gaussint!(0, 1).to_latex(), "i");
+        assert_eq!(gaussint!(0, -1).to_latex(), "-i");
+        assert_eq!(gaussint!(0, 4).to_latex(), "4i");
+        assert_eq!(gaussint!(0, -4).to_latex(), "-4i");
+        assert_eq!(gaussint!(2, 1).to_latex(), "2 + i");
+        assert_eq!(gaussint!(2, -1).to_latex(), "2 - i");
+        assert_eq!(gaussint!(2, 3).to_latex(), "2 + 3i");
+        assert_eq!(gaussint!(2, -3).to_latex(), "2 - 3i");
+        assert_eq!(gaussint!(-2, -3).to_latex(), "-2 - 3i");
+        assert_eq!(gaussint!(5u32, 1).to_latex(), "5 + i");
+        // Parts that do not fit in an i128, or whose absolute value does not.
+        assert_eq!(
+            GaussianInt::<i128>::new(1, i128::MIN).to_latex(),
+            format!("1 - {}i", i128::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            GaussianInt::<u128>::new(u128::MAX, 1).to_latex(),
+            format!("{} + i", u128::MAX)
+        );
+
+        // The same terms as `Display`, with spaces around the operator.
+        for z in get_g_ints(5) {
+            assert_eq!(
+                z.to_latex().replace(" + ", "+").replace(" - ", "-"),
+                z.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn divisor_pairs() {
+        let ten = gaussint!(10, 0);
+        let pairs = ten.divisor_pairs();
+        // 10 = -i(1 + i)²(2 + i)(2 - i), so there are 3 · 2 · 2 divisors up to units.
+        assert_eq!(pairs.len(), 12);
+        for &(d, e) in &pairs {
+            assert_eq!(d * e, ten);
+            assert!((d * e).is_associated(ten));
+            assert_eq!(ten.complementary_divisor(d), Some(e));
+            assert_eq!(d.canonical_associate(), d);
+        }
+        let norms: Vec<_> = pairs.iter().map(|(d, _)| d.norm()).collect();
+        assert_eq!(norms, vec![1, 2, 4, 5, 5, 10, 10, 20, 20, 25, 50, 100]);
+
+        assert_eq!(
+            ten.complementary_divisor(gaussint!(3, 1)),
+            Some(gaussint!(3, -1))
+        );
+        assert_eq!(ten.complementary_divisor(gaussint!(3, 0)), None);
+        assert_eq!(ten.complementary_divisor(gaussint!(0, 0)), None);
+    }
+
+    #[test]
+    fn gaussian_prime_over() {
+        assert_eq!(crate::gaussian_prime_over(2), Some(gaussint!(1, 1)));
+        assert_eq!(crate::gaussian_prime_over(5), Some(gaussint!(2, 1)));
+        assert_eq!(crate::gaussian_prime_over(13), Some(gaussint!(3, 2)));
+        assert_eq!(crate::gaussian_prime_over(7), None);
+        assert_eq!(crate::gaussian_prime_over(3), None);
+        assert_eq!(crate::gaussian_prime_over(25), None);
+        assert_eq!(crate::gaussian_prime_over(1), None);
+
+        for p in (3..2000u64).filter(|p| p % 4 == 1 && primal::is_prime(*p)) {
+            let q = crate::gaussian_prime_over(p).unwrap();
+            assert!(q.is_gaussian_prime());
+            assert!(q.0.re > q.0.im && q.0.im > 0);
+            assert_eq!(q * q.conj(), gaussint!(p as i64, 0));
+        }
+    }
+
+    #[test]
+    fn sigma_norm() {
+        for z in [
+            gaussint!(5, 0),
+            gaussint!(12, 0),
+            gaussint!(3, 4),
+            gaussint!(7, 9),
+            gaussint!(0, 1),
+        ] {
+            let mut brute = 0;
+            for d in get_g_ints(12).filter(|d| d.canonical_associate() == *d && d.divides(z)) {
+                brute += d.norm() as u64;
+            }
+            assert_eq!(z.sigma_norm(), brute, "{z}");
+        }
+        // A prime power π³ has divisors 1, π, π², π³ up to units.
+        let p = gaussint!(2, 3);
+        assert_eq!(p.pow(3).sigma_norm(), 1 + 13 + 13 * 13 + 13 * 13 * 13);
+        assert_eq!(gaussint!(3, 0).pow(2).sigma_norm(), 1 + 9 + 81);
+    }
+
+    #[test]
+    fn units_are_not_gaussian_primes() {
+        for u in GaussianInt::<isize>::units() {
+            assert!(!u.is_gaussian_prime(), "{u}");
+            assert_eq!(u.gaussian_prime_kind(), None);
+            assert_eq!(u.lies_over(), None);
+        }
+        // 1 + i is prime, unlike the unit 1 or the ramified rational prime 2.
+        for z in gaussint!(1, 1).unit_orbit().take(4) {
+            assert!(z.is_gaussian_prime(), "{z}");
+            assert_eq!(
+                z.gaussian_prime_kind(),
+                Some(GaussianPrimeKind::NormIsPrime)
+            );
+        }
+        for z in gaussint!(2, 0).unit_orbit().take(4) {
+            assert!(!z.is_gaussian_prime(), "{z}");
+        }
+        assert!(!gaussint!(0, 0).is_gaussian_prime());
+        assert!(!gaussint!(1u32, 0).is_gaussian_prime());
+        assert!(gaussint!(1u32, 1).is_gaussian_prime());
+        assert!(!gaussint!(2u32, 0).is_gaussian_prime());
+        assert!(!gaussint!(0u32, 2).is_gaussian_prime());
+    }
+
+    #[test]
+    fn norm_is_prime() {
+        assert!(gaussint!(2, 1).norm_is_prime());
+        assert!(gaussint!(1, 1).norm_is_prime());
+        assert!(!gaussint!(3, 0).norm_is_prime());
+        assert!(!gaussint!(1, 0).norm_is_prime());
+        assert!(!gaussint!(0, 0).norm_is_prime());
+        for z in get_g_ints(20).filter(|z| z.0.re != 0 && z.0.im != 0) {
+            assert_eq!(z.norm_is_prime(), z.is_gaussian_prime(), "{z}");
+        }
+        // The norm overflows i64, but not the widened computation.
+        let z = gaussint!(3_000_000_000i64, 1_000_000_001);
+        assert_eq!(z.norm_is_prime(), primal::is_prime(z.norm_wide() as u64));
+        // The norm 18000000246000001681 is prime, and a² + b² is close to u64::MAX.
+        let z = gaussint!(3_000_000_000i64, 3_000_000_041);
+        assert!(z.norm_is_prime());
+        assert!(z.is_gaussian_prime());
+    }
+
+    #[test]
+    #[should_panic(expected = "norm should fit in a u64")]
+    fn is_gaussian_prime_norm_overflow() {
+        // i64::MAX² overflows a u64, so the norm cannot be tested.
+        gaussint!(i64::MAX, 1).is_gaussian_prime();
+    }
+
+    #[test]
+    fn unbounded_gaussian_primes() {
+        let first: Vec<_> = crate::gaussian_primes().take(5).collect();
+        assert_eq!(
+            first,
+            vec![
+                gaussint!(-1, -1),
+                gaussint!(-1, 1),
+                gaussint!(1, -1),
+                gaussint!(1, 1),
+                gaussint!(-2, -1),
+            ]
+        );
+
+        // The same primes as a bounded search, in order of norm.
+        let primes: Vec<_> = crate::gaussian_primes()
+            .take_while(|p| p.norm() <= 400)
+            .collect();
+        assert!(primes.windows(2).all(|w| w[0].norm() <= w[1].norm()));
+        let mut expected: Vec<_> = get_g_primes(20)
+            .filter(|p| p.norm() <= 400)
+            .map(|p| gaussint!(p.0.re as i64, p.0.im as i64))
+            .collect();
+        expected.sort_by_key(|p| (p.norm(), p.0.re, p.0.im));
+        assert_eq!(primes, expected);
+    }
+
+    #[test]
+    fn checked_and_saturating_sub() {
+        let z: GaussianInt<u8> = gaussint!(10, 200);
+        let w: GaussianInt<u8> = gaussint!(20, 100);
+        assert_eq!(z.checked_sub(w), None);
+        assert_eq!(w.checked_sub(z), None);
+        assert_eq!(z.saturating_sub(w), gaussint!(0, 100));
+        assert_eq!(w.saturating_sub(z), gaussint!(10, 0));
+        assert_eq!(z.checked_sub(gaussint!(10, 0)), Some(gaussint!(0, 200)));
+        assert_eq!(z.saturating_sub(z), gaussint!(0, 0));
+        assert_eq!(z.checked_add(w), None);
+        assert_eq!(z.checked_add(gaussint!(245, 55)), Some(gaussint!(255, 255)));
+
+        for z in get_g_ints(5) {
+            for w in get_g_ints(5) {
+                assert_eq!(z.checked_sub(w), Some(z - w));
+                assert_eq!(z.saturating_sub(w), z - w);
+                assert_eq!(z.checked_add(w), Some(z + w));
+            }
+        }
+        let max = gaussint!(i8::MAX, i8::MIN);
+        assert_eq!(max.saturating_sub(gaussint!(-1, 1)), max);
+    }
+
+    #[test]
+    fn parse_many() {
+        let values = GaussianInt::<i64>::parse_many("1+i, -5-4i, 3i, 3").unwrap();
+        assert_eq!(
+            values,
+            vec![
+                gaussint!(1, 1),
+                gaussint!(-5, -4),
+                gaussint!(0, 3),
+                gaussint!(3, 0)
+            ]
+        );
+        assert_eq!(GaussianInt::<i64>::parse_many(" \n"), Ok(vec![]));
+        assert_eq!(
+            GaussianInt::<i64>::parse_many("1,,2\n\t-i").unwrap().len(),
+            3
+        );
+
+        let error = GaussianInt::<i64>::parse_many("1+i, -5-4i, 3k, 3").unwrap_err();
+        assert_eq!(error.index(), Some(2));
+        assert_eq!(
+            error.to_string(),
+            "invalid Gaussian integer literal at index 2"
+        );
+        let error = GaussianInt::<u8>::parse_many("1 300").unwrap_err();
+        assert_eq!(error.index(), Some(1));
+
+        // A table printed with `Display` reads back in.
+        let primes: Vec<_> = crate::gaussian_primes().take(40).collect();
+        let table = primes
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        assert_eq!(GaussianInt::parse_many(&table), Ok(primes));
+        assert_eq!(
+            GaussianInt::<i32>::from_str_strict("i+1")
+                .unwrap_err()
+                .index(),
+            None
+        );
+    }
+
+    #[test]
+    fn gcd_with_unit() {
+        for a in get_g_ints(5) {
+            for b in get_g_ints(5) {
+                let (g, u) = GaussianInt::gcd_with_unit(a, b);
+                assert_eq!(g, GaussianInt::gcd(a, b));
+                assert!(GaussianInt::units().contains(&u));
+                // Undoing the unit recovers an associate of g that the algorithm produced.
+                let raw = g * u.conj();
+                assert_eq!(u * raw, g);
+                assert!(raw.divides(a) || raw.is_zero());
+                assert!(raw.divides(b) || raw.is_zero());
+            }
+        }
+        assert_eq!(
+            GaussianInt::gcd_with_unit(gaussint!(0, 0), gaussint!(0, 0)),
+            (gaussint!(0, 0), gaussint!(1, 0))
+        );
+        // gcd(0, 3i) ends at 3i, which is rotated by -i.
+        assert_eq!(
+            GaussianInt::gcd_with_unit(gaussint!(0, 0), gaussint!(0, 3)),
+            (gaussint!(3, 0), gaussint!(0, -1))
+        );
+    }
+
+    #[test]
+    fn cayley_tables() {
+        // ℤ[i]/(1 + i) is the field with two elements.
+        let (zero, one) = (gaussint!(0, 0), gaussint!(1, 0));
+        let m = gaussint!(1, 1);
+        assert_eq!(GaussianInt::residue_system(m), vec![zero, one]);
+        assert_eq!(
+            GaussianInt::addition_table(m),
+            vec![vec![zero, one], vec![one, zero]]
+        );
+        assert_eq!(
+            GaussianInt::multiplication_table(m),
+            vec![vec![zero, zero], vec![zero, one]]
+        );
+
+        for m in [
+            gaussint!(2, 0),
+            gaussint!(3, 0),
+            gaussint!(2, 1),
+            gaussint!(1, 3),
+        ] {
+            let residues = GaussianInt::residue_system(m);
+            assert_eq!(residues.len(), m.norm());
+            let add = GaussianInt::addition_table(m);
+            let mul = GaussianInt::multiplication_table(m);
+            for (j, &a) in residues.iter().enumerate() {
+                // Each row of the addition table is a permutation of the residues.
+                let mut row = add[j].clone();
+                row.sort_by_key(|z| (z.0.im, z.0.re));
+                assert_eq!(row, residues);
+                for (k, &b) in residues.iter().enumerate() {
+                    assert_eq!(add[j][k], (a + b).reduce_mod(m));
+                    assert_eq!(mul[j][k], mul[k][j]);
+                    assert!(mul[j][k].congruent(a * b, m));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_irreducible() {
+        for z in get_g_ints(30) {
+            assert_eq!(z.is_irreducible(), z.is_gaussian_prime(), "{z}");
+        }
+        // Irreducible by definition: no factorization into two non-units.
+        let non_units: Vec<_> = get_g_ints(6).filter(|z| z.norm() > 1).collect();
+        for z in get_g_ints(6).filter(|z| z.norm() > 1) {
+            let factors = non_units
+                .iter()
+                .any(|&a| non_units.iter().any(|&b| a * b == z));
+            assert_eq!(z.is_irreducible(), !factors, "{z}");
+        }
+    }
+
+    #[test]
+    fn try_mul() {
+        let z: GaussianInt<i16> = gaussint!(100, 100);
+        assert_eq!(z.try_mul(gaussint!(1, -1)), Ok(gaussint!(200, 0)));
+        assert_eq!(z.try_mul(gaussint!(0, 1)), Ok(gaussint!(-100, 100)));
+        // 2·100·100 = 20000 fits, but 190·190 does not.
+        assert_eq!(z.try_mul(z), Ok(gaussint!(0, 20000)));
+        let w: GaussianInt<i16> = gaussint!(190, 1);
+        let error = w.try_mul(w).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "arithmetic overflow in Gaussian integer multiplication"
+        );
+        // The parts fit, but an intermediate product does not.
+        let big: GaussianInt<i16> = gaussint!(200, 200);
+        assert!(big.try_mul(gaussint!(200, -200)).is_err());
+
+        for a in get_g_ints(4) {
+            for b in get_g_ints(4) {
+                assert_eq!(a.try_mul(b), Ok(a * b));
+            }
+        }
+    }
+
+    #[test]
+    fn with_norm() {
+        let five: Vec<_> = GaussianInt::with_norm(5).collect();
+        assert_eq!(five.len(), 8);
+        assert!(five.iter().all(|z| z.norm() == 5));
+        assert_eq!(
+            GaussianInt::with_norm(0).collect::<Vec<_>>(),
+            vec![gaussint!(0, 0)]
+        );
+        assert_eq!(GaussianInt::with_norm(1).count(), 4);
+        assert_eq!(GaussianInt::with_norm(21).count(), 0);
+
+        // Against the norm histogram, which is exact for norms up to n².
+        let histogram = crate::norm_histogram(12);
+        for n in 0..=144 {
+            let expected = histogram.get(&n).copied().unwrap_or(0);
+            assert_eq!(GaussianInt::with_norm(n).count(), expected, "n = {n}");
+            assert!(GaussianInt::with_norm(n).all(|z| z.norm() as u64 == n));
+        }
+    }
+
+    #[test]
+    fn group_conjugate_pairs() {
+        let primes = [gaussint!(2, 1), gaussint!(2, -1), gaussint!(3, 0)];
+        let pairs = crate::group_conjugate_pairs(&primes);
+        assert_eq!(pairs, vec![(gaussint!(2, 1), gaussint!(2, -1))]);
+        let leftover: Vec<_> = primes
+            .iter()
+            .filter(|p| !pairs.iter().any(|(a, b)| a == *p || b == *p))
+            .collect();
+        assert_eq!(leftover, vec![&gaussint!(3, 0)]);
+
+        // Associates of the conjugate do not count, and each entry pairs once.
+        let primes = [
+            gaussint!(1, 2),
+            gaussint!(-1, 2),
+            gaussint!(1, -2),
+            gaussint!(1, -2),
+        ];
+        assert_eq!(
+            crate::group_conjugate_pairs(&primes),
+            vec![(gaussint!(1, 2), gaussint!(1, -2))]
+        );
+
+        // The two primes over a split prime form a pair.
+        for p in [5, 13, 17, 29] {
+            let q = crate::gaussian_prime_over(p).unwrap();
+            assert_eq!(q.conjugate_partner(), q.conj());
+            assert_eq!(q * q.conjugate_partner(), gaussint!(p as i64, 0));
+            assert_eq!(
+                crate::group_conjugate_pairs(&[q, q.conjugate_partner()]),
+                vec![(q, q.conj())]
+            );
+        }
+    }
+
+    #[test]
+    fn circle_lattice_count() {
+        assert_eq!(crate::circle_lattice_count(0), 1);
+        // 0, the four units, the four of norm 2, and ±2, ±2i
+        assert_eq!(crate::circle_lattice_count(2), 13);
+        for r in 0..=15u64 {
+            let brute = get_g_ints(r as isize)
+                .filter(|z| z.norm() as u64 <= r * r)
+                .count() as u64;
+            assert_eq!(crate::circle_lattice_count(r), brute, "r = {r}");
+        }
+        // The sum of r₂(n) over n ≤ r².
+        let from_histogram: usize = crate::norm_histogram(10)
+            .range(..=100)
+            .map(|(_, c)| c)
+            .sum();
+        assert_eq!(crate::circle_lattice_count(10), from_histogram as u64);
+    }
+
+    #[test]
+    fn rem_with_rounding() {
+        use crate::RoundingMode::*;
+        let (a, b) = (gaussint!(1, 1), gaussint!(2, 0));
+        assert_eq!(a.rem_with_rounding(b, NearestTiesUp), gaussint!(-1, -1));
+        assert_eq!(a.rem_with_rounding(b, NearestTiesToEven), gaussint!(1, 1));
+        assert_eq!(a.rem_with_rounding(b, TowardZero), gaussint!(1, 1));
+        // -3/2 rounds up to -1, but ties to the even -2, and truncates to -1.
+        let a = gaussint!(3, -3);
+        assert_eq!(a.rem_with_rounding(b, NearestTiesUp), gaussint!(-1, -1));
+        assert_eq!(a.rem_with_rounding(b, NearestTiesToEven), gaussint!(-1, 1));
+        assert_eq!(a.rem_with_rounding(b, TowardZero), gaussint!(1, -1));
+
+        for a in get_g_ints(6) {
+            for b in get_g_ints(3).filter(|b| !b.is_zero()) {
+                assert_eq!(a.rem_with_rounding(b, NearestTiesUp), a.rem_euclid(b));
+                assert_eq!(a.rem_with_rounding(b, NearestTiesUp), a % b);
+                let r = a.rem_with_rounding(b, NearestTiesToEven);
+                assert!(r.congruent(a, b));
+                assert!(2 * r.norm() <= b.norm());
+            }
+        }
+    }
+
+    #[test]
+    fn powers_until_norm() {
+        let powers = gaussint!(1, 1).powers_until_norm(16);
+        assert_eq!(
+            powers,
+            vec![
+                gaussint!(1, 1),
+                gaussint!(0, 2),
+                gaussint!(-2, 2),
+                gaussint!(-4, 0)
+            ]
+        );
+        let norms: Vec<_> = powers.iter().map(|z| z.norm()).collect();
+        assert_eq!(norms, vec![2, 4, 8, 16]);
+        assert_eq!(gaussint!(1, 1).powers_until_norm(31).len(), 4);
+        assert!(gaussint!(1, 1).powers_until_norm(1).is_empty());
+        assert_eq!(
+            gaussint!(2, 1).powers_until_norm(125),
+            gaussint!(2, 1).powers_until_norm(624)
+        );
+
+        assert_eq!(
+            gaussint!(0, 1).powers_until_norm(10),
+            vec![
+                gaussint!(0, 1),
+                gaussint!(-1, 0),
+                gaussint!(0, -1),
+                gaussint!(1, 0)
+            ]
+        );
+        assert_eq!(
+            gaussint!(-1, 0).powers_until_norm(10),
+            vec![gaussint!(-1, 0), gaussint!(1, 0)]
+        );
+        assert_eq!(gaussint!(0, 0).powers_until_norm(0), vec![gaussint!(0, 0)]);
+
+        // 3⁵ overflows an i8, but its norm is out of range, so it is never computed.
+        let z: GaussianInt<i8> = gaussint!(3, 0);
+        assert_eq!(
+            z.powers_until_norm(81 * 81),
+            vec![gaussint!(3), gaussint!(9), gaussint!(27), gaussint!(81)]
+        );
+    }
+
+    #[test]
+    fn are_gaussian_prime() {
+        let values: Vec<_> = get_g_ints(5).collect();
+        let expected: Vec<_> = values.iter().map(|z| z.is_gaussian_prime()).collect();
+        assert_eq!(GaussianInt::are_gaussian_prime(&values), expected);
+
+        assert!(GaussianInt::<i64>::are_gaussian_prime(&[]).is_empty());
+        // Norms past the sieve limit.
+        let values = [
+            gaussint!(1i64 << 13, 3),
+            gaussint!(5_000_011, 0),
+            gaussint!(3_000_000_000, 7),
+        ];
+        let expected: Vec<_> = values.iter().map(|z| z.is_gaussian_prime()).collect();
+        assert_eq!(GaussianInt::are_gaussian_prime(&values), expected);
+    }
+
+    #[test]
+    fn next_prime_in_direction() {
+        let origin = gaussint!(0, 0);
+        let p = origin.next_prime_in_direction(gaussint!(1, 0));
+        assert!(p.is_gaussian_prime());
+        assert_eq!(p, gaussint!(3, 0));
+
+        for z in get_g_ints(4) {
+            for step in [
+                gaussint!(1, 0),
+                gaussint!(0, -1),
+                gaussint!(1, 2),
+                gaussint!(-3, 1),
+            ] {
+                if GaussianInt::gcd(z, step).norm() > 1 {
+                    continue;
+                }
+                let p = z.next_prime_in_direction(step);
+                assert!(p.is_gaussian_prime());
+                // p = z + k·step for some k ≥ 1, with no prime before it.
+                let k = (1..).find(|&k| z + step * gaussint!(k) == p).unwrap();
+                assert!((1..k).all(|j| !(z + step * gaussint!(j)).is_gaussian_prime()));
+            }
+        }
+        // Every point of this ray is divisible by the prime 1 + i,
+        // and the first one is its associate -1 - i.
+        assert_eq!(
+            gaussint!(-3, -3).next_prime_in_direction(gaussint!(2, 2)),
+            gaussint!(-1, -1)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "no Gaussian prime")]
+    fn next_prime_in_direction_without_primes() {
+        gaussint!(0, 0).next_prime_in_direction(gaussint!(2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be nonzero")]
+    fn next_prime_in_direction_zero_step() {
+        gaussint!(1, 0).next_prime_in_direction(gaussint!(0, 0));
+    }
+
+    #[test]
+    fn json() {
+        for z in get_g_ints(3) {
+            assert_eq!(GaussianInt::from_json(&z.to_json()), Ok(z));
+        }
+        let z: GaussianInt<i64> = gaussint!(i64::MIN, i64::MAX);
+        assert_eq!(GaussianInt::from_json(&z.to_json()), Ok(z));
+        let z: GaussianInt<u128> = gaussint!(u128::MAX, 0);
+        assert_eq!(GaussianInt::from_json(&z.to_json()), Ok(z));
+        let z: GaussianInt<i128> = gaussint!(i128::MIN, i128::MAX);
+        assert_eq!(GaussianInt::from_json(&z.to_json()), Ok(z));
+        assert_eq!(gaussint!(-5, -4).to_json(), "[-5, -4]");
+        assert_eq!(gaussint!(0, 0).to_json(), "[0, 0]");
+        assert_eq!(GaussianInt::from_json(" [ -5 ,4 ] "), Ok(gaussint!(-5, 4)));
+
+        for bad in [
+            "",
+            "[]",
+            "[1]",
+            "[1, 2, 3]",
+            "1, 2",
+            "[1, 2",
+            "[+1, 2]",
+            "[1.5, 2]",
+            "[01, 2]",
+            "[1, i]",
+        ] {
+            assert!(GaussianInt::<i32>::from_json(bad).is_err(), "{bad:?}");
+        }
+        assert!(GaussianInt::<u8>::from_json("[256, 0]").is_err());
+        assert!(GaussianInt::<u8>::from_json("[-1, 0]").is_err());
+    }
+
+    #[test]
+    fn gcd_lattice_path() {
+        for a in get_g_ints(8) {
+            for b in get_g_ints(8).filter(|b| b.norm() < a.norm()) {
+                let path = GaussianInt::gcd_lattice_path(a, b);
+                assert!(path.windows(2).all(|w| w[1].norm() < w[0].norm()));
+                assert_eq!(path.last(), Some(&gaussint!(0, 0)));
+                let g = path[path.len() - 2];
+                let (gcd, u) = GaussianInt::gcd_with_unit(a, b);
+                assert_eq!(
+                    gaussint!(gcd.0.re as i64, gcd.0.im as i64),
+                    g * gaussint!(u.0.re as i64, u.0.im as i64)
+                );
+            }
+        }
+        assert_eq!(
+            GaussianInt::gcd_lattice_path(gaussint!(3, 0), gaussint!(0, 0)),
+            vec![gaussint!(3, 0), gaussint!(0, 0)]
+        );
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(GaussianInt::try_from("1-i"), Ok(gaussint!(1, -1)));
+        let z: GaussianInt<i64> = "-5-4i".try_into().unwrap();
+        assert_eq!(z, gaussint!(-5, -4));
+        assert!(GaussianInt::<i32>::try_from("1-x").is_err());
+        for z in get_g_ints(3) {
+            assert_eq!(GaussianInt::try_from(z.to_string().as_str()), Ok(z));
+        }
+    }
+
+    #[test]
+    fn norm_class() {
+        assert_eq!(gaussint!(0, 0).norm_class(), NormClass::Zero);
+        assert_eq!(gaussint!(1, 0).norm_class(), NormClass::Unit);
+        assert_eq!(gaussint!(0, -1).norm_class(), NormClass::Unit);
+        assert_eq!(gaussint!(2, 1).norm_class(), NormClass::NormPrime);
+        assert_eq!(gaussint!(1, 1).norm_class(), NormClass::NormPrime);
+        assert_eq!(gaussint!(3, 0).norm_class(), NormClass::NormPrimeSquared);
+        assert_eq!(gaussint!(2, 0).norm_class(), NormClass::NormPrimeSquared);
+        assert_eq!(gaussint!(3, 4).norm_class(), NormClass::NormPrimeSquared);
+        assert_eq!(gaussint!(1, 3).norm_class(), NormClass::Composite);
+
+        for z in get_g_ints(20) {
+            match z.norm_class() {
+                NormClass::Zero | NormClass::Unit | NormClass::Composite => {
+                    assert!(!z.is_gaussian_prime())
+                }
+                NormClass::NormPrime => assert!(z.is_gaussian_prime()),
+                NormClass::NormPrimeSquared => {
+                    let p = crate::isqrt(z.norm());
+                    let inert = z.is_associate_of_rational() && p % 4 == 3;
+                    assert_eq!(z.is_gaussian_prime(), inert);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reflections() {
+        assert_eq!(gaussint!(2, 3).reflect_diagonal(), gaussint!(3, 2));
+        assert_eq!(gaussint!(2, 3).reflect_imag(), gaussint!(-2, 3));
+        assert_eq!(gaussint!(2, 3).reflect_real(), gaussint!(2, -3));
+        let i = gaussint!(0, 1);
+        for z in get_g_ints(4) {
+            assert_eq!(z.reflect_imag().reflect_imag(), z);
+            assert_eq!(z.reflect_real().reflect_real(), z);
+            assert_eq!(z.reflect_diagonal().reflect_diagonal(), z);
+            assert_eq!(z.reflect_real(), z.conj());
+            assert_eq!(z.reflect_imag(), -z.conj());
+            // Two reflections make a rotation: across the real axis, then the diagonal,
+            // is a quarter turn.
+            assert_eq!(z.reflect_real().reflect_diagonal(), z * i);
+            assert_eq!(z.reflect_real().reflect_imag(), -z);
+            assert_eq!(z.reflect_diagonal().norm(), z.norm());
+        }
+    }
+
+    #[test]
+    fn d4_orbit() {
+        let orbit = gaussint!(2, 1).d4_orbit();
+        assert_eq!(orbit.len(), 8);
+        for z in [
+            gaussint!(2, 1),
+            gaussint!(1, 2),
+            gaussint!(-2, 1),
+            gaussint!(2, -1),
+            gaussint!(-1, -2),
+        ] {
+            assert!(orbit.contains(&z));
+        }
+        assert_eq!(gaussint!(1, 1).d4_orbit().len(), 4);
+        assert_eq!(gaussint!(0, 3).d4_orbit().len(), 4);
+        assert_eq!(gaussint!(0, 0).d4_orbit(), vec![gaussint!(0, 0)]);
+
+        for z in get_g_ints(6) {
+            let orbit = z.d4_orbit();
+            assert_eq!(orbit[0], z);
+            assert!(orbit.iter().all(|w| w.norm() == z.norm()));
+            assert!(orbit
+                .iter()
+                .all(|w| w.is_gaussian_prime() == z.is_gaussian_prime()));
+            assert!(orbit.contains(&z.reflect_diagonal()));
+            assert!(orbit.contains(&z.reflect_imag()));
+        }
+    }
+
+    #[test]
+    fn checked_div() {
+        for a in get_g_ints(5) {
+            assert_eq!(a.checked_div(gaussint!(0, 0)), None);
+            for b in get_g_ints(3).filter(|b| !b.is_zero()) {
+                assert_eq!(a.checked_div(b), Some(a.round_div(b)));
+            }
+        }
+        let big: GaussianInt<i32> = gaussint!(1 << 29, 1 << 29);
+        assert_eq!(big.checked_div(gaussint!(1 << 20, 1)), None);
+        assert_eq!(big.checked_div(gaussint!(1, 0)), Some(big));
+    }
+
+    #[test]
+    fn first_quadrant_primes() {
+        let primes: Vec<_> = crate::first_quadrant_primes(500).collect();
+        assert!(primes.windows(2).all(|w| w[0].norm() <= w[1].norm()));
+        for p in &primes {
+            assert!(p.0.re >= p.0.im && p.0.im >= 0);
+            assert!(p.is_prime_representative());
+        }
+        // Each representative is a first-quadrant prime or the swap of one.
+        let representatives = get_g_primes(23)
+            .filter(|p| p.is_prime_representative() && p.norm() <= 500)
+            .map(|p| gaussint!(p.0.re as i64, p.0.im as i64))
+            .collect::<Vec<_>>();
+        for p in &representatives {
+            let swapped = gaussint!(p.0.im, p.0.re);
+            assert!(primes.contains(p) || primes.contains(&swapped));
+        }
+        let split = primes
+            .iter()
+            .filter(|p| p.0.re > p.0.im && p.0.im > 0)
+            .count();
+        assert_eq!(representatives.len(), primes.len() + split);
+        assert_eq!(
+            (primes.len() + split) as u64,
+            crate::prime_count_up_to_norm(500)
+        );
+        assert_eq!(crate::first_quadrant_primes(1).count(), 0);
+    }
+
+    #[test]
+    fn div_rem() {
+        for a in get_g_ints(8) {
+            for b in get_g_ints(4).filter(|b| !b.is_zero()) {
+                let (q, r) = a.div_rem(b);
+                assert_eq!(q * b + r, a);
+                assert!(2 * r.norm() <= b.norm());
+                assert_eq!((q, r), (a / b, a % b));
+            }
+        }
+    }
 }
@@ -0,0 +1,83 @@
+use crate::GaussianInt;
+use num_integer::Integer;
+use num_traits::{PrimInt, WrappingAdd, WrappingMul, WrappingSub};
+
+/// Wrapping arithmetic.
+///
+/// These methods wrap around at the boundary of `T` instead of overflowing,
+/// so they work for unsigned types.
+/// For `GaussianInt<u64>` this is arithmetic in ℤ\[*i*\] modulo 2⁶⁴.
+impl<T> GaussianInt<T>
+where
+    T: PrimInt + Integer + WrappingAdd + WrappingSub + WrappingMul,
+{
+    /// Wrapping addition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u8> = GaussianInt::new(200, 1);
+    /// assert_eq!(z.wrapping_add(z), GaussianInt::new(144, 2));
+    /// # }
+    /// ```
+    pub fn wrapping_add(&self, other: Self) -> Self {
+        Self::new(
+            self.0.re.wrapping_add(&other.0.re),
+            self.0.im.wrapping_add(&other.0.im),
+        )
+    }
+
+    /// Wrapping subtraction.
+    pub fn wrapping_sub(&self, other: Self) -> Self {
+        Self::new(
+            self.0.re.wrapping_sub(&other.0.re),
+            self.0.im.wrapping_sub(&other.0.im),
+        )
+    }
+
+    /// Wrapping multiplication.
+    pub fn wrapping_mul(&self, other: Self) -> Self {
+        let (a, b) = (self.0.re, self.0.im);
+        let (c, d) = (other.0.re, other.0.im);
+        Self::new(
+            a.wrapping_mul(&c).wrapping_sub(&b.wrapping_mul(&d)),
+            a.wrapping_mul(&d).wrapping_add(&b.wrapping_mul(&c)),
+        )
+    }
+
+    /// Wrapping negation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u8> = GaussianInt::new(1, 2);
+    /// assert_eq!(z.wrapping_neg(), GaussianInt::new(255, 254));
+    /// assert_eq!(z.wrapping_add(z.wrapping_neg()), GaussianInt::new(0, 0));
+    /// # }
+    /// ```
+    pub fn wrapping_neg(&self) -> Self {
+        Self::new(
+            T::zero().wrapping_sub(&self.0.re),
+            T::zero().wrapping_sub(&self.0.im),
+        )
+    }
+
+    /// Wrapping complex conjugate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use gaussiant::GaussianInt;
+    /// # fn main() {
+    /// let z: GaussianInt<u8> = GaussianInt::new(3, 2);
+    /// assert_eq!(z.wrapping_conj(), GaussianInt::new(3, 254));
+    /// # }
+    /// ```
+    pub fn wrapping_conj(&self) -> Self {
+        Self::new(self.0.re, T::zero().wrapping_sub(&self.0.im))
+    }
+}